@@ -0,0 +1,188 @@
+use crate::{
+    error::{Error, Result},
+    settings,
+    version::GameVersion,
+};
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// File name of the user's addon registry inside the config directory.
+pub const REGISTRY_FILE_NAME: &str = "addons.toml";
+
+const WA4_SUFFIX: &str = "ctdveirvrtdice";
+const WA5_SUFFIX: &str = "vridtcetvrdice";
+
+/// An addon configuration whose suffix is appended to the Battle.net ID before encoding.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AddonConfig {
+    pub name: String,
+    pub suffix: String,
+    /// Game versions this config generates codes for. Defaults to every version.
+    #[serde(default = "all_versions")]
+    pub versions: Vec<GameVersion>,
+    /// Whether this config is used when generating codes for its versions.
+    #[serde(default)]
+    pub default: bool,
+}
+
+impl AddonConfig {
+    pub fn new(name: &str, suffix: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            suffix: suffix.to_string(),
+            versions: all_versions(),
+            default: false,
+        }
+    }
+
+    pub fn applies_to(&self, version: GameVersion) -> bool {
+        self.versions.contains(&version)
+    }
+}
+
+fn all_versions() -> Vec<GameVersion> {
+    GameVersion::ALL.to_vec()
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RegistryFile {
+    #[serde(default, rename = "addon")]
+    addons: Vec<AddonConfig>,
+}
+
+/// The addon configurations known to the generator.
+///
+/// The built-in WA4 and WA5 configs are always present. A registry file can
+/// override them by name or add new ones, so a rotated suffix does not need a release.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddonRegistry {
+    configs: Vec<AddonConfig>,
+    /// Names of configs added with [`AddonRegistry::register`]; their defaults
+    /// win over the built-in one for the versions they apply to.
+    registered: Vec<String>,
+}
+
+impl Default for AddonRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl AddonRegistry {
+    /// The configs shipped with the generator: WA4, and WA5 as the default.
+    pub fn builtin() -> Self {
+        Self {
+            configs: vec![
+                AddonConfig::new("WA4", WA4_SUFFIX),
+                AddonConfig {
+                    default: true,
+                    ..AddonConfig::new("WA5", WA5_SUFFIX)
+                },
+            ],
+            registered: Vec::new(),
+        }
+    }
+
+    /// Loads the registry file from the user's config directory, falling back
+    /// to the built-ins when it does not exist.
+    pub fn load() -> Result<Self> {
+        match default_path() {
+            Some(path) if path.exists() => Self::load_from(&path),
+            _ => Ok(Self::builtin()),
+        }
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path).map_err(|e| {
+            Error::InvalidRegistry(format!("could not read '{}': {e}", path.display()))
+        })?;
+
+        Self::from_toml(&text)
+    }
+
+    /// Parses `[[addon]]` tables and merges them over the built-ins.
+    ///
+    /// A config in `text` marked as default replaces the built-in default for
+    /// the versions it applies to.
+    pub fn from_toml(text: &str) -> Result<Self> {
+        let file: RegistryFile =
+            toml::from_str(text).map_err(|e| Error::InvalidRegistry(e.message().to_string()))?;
+
+        let mut registry = Self::builtin();
+        for addon in file.addons {
+            registry.register(addon)?;
+        }
+
+        Ok(registry)
+    }
+
+    /// Adds `addon`, replacing any config with the same name.
+    pub fn register(&mut self, addon: AddonConfig) -> Result<()> {
+        if addon.name.trim().is_empty() {
+            return Err(Error::InvalidRegistry("addon name is empty".to_string()));
+        }
+        if addon.suffix.is_empty() {
+            return Err(Error::InvalidRegistry(format!(
+                "addon '{}' has an empty suffix",
+                addon.name
+            )));
+        }
+
+        if !self.is_registered(&addon.name) {
+            self.registered.push(addon.name.trim().to_string());
+        }
+        match self.position(&addon.name) {
+            Some(index) => self.configs[index] = addon,
+            None => self.configs.push(addon),
+        }
+        Ok(())
+    }
+
+    pub fn configs(&self) -> &[AddonConfig] {
+        &self.configs
+    }
+
+    /// Looks up a config by name, ignoring ASCII case.
+    pub fn get(&self, name: &str) -> Option<&AddonConfig> {
+        self.position(name).map(|index| &self.configs[index])
+    }
+
+    /// The config used to generate codes for `version`: the first applicable
+    /// registered config marked as default, then the first applicable built-in
+    /// one, otherwise the first applicable config.
+    pub fn default_for(&self, version: GameVersion) -> Result<&AddonConfig> {
+        let applicable = self
+            .configs
+            .iter()
+            .filter(|config| config.applies_to(version));
+        let mut defaults = applicable.clone().filter(|config| config.default);
+
+        defaults
+            .clone()
+            .find(|config| self.is_registered(&config.name))
+            .or_else(|| defaults.next())
+            .or_else(|| applicable.clone().next())
+            .ok_or(Error::NoAddonConfig(version))
+    }
+
+    fn is_registered(&self, name: &str) -> bool {
+        self.registered
+            .iter()
+            .any(|registered| registered.eq_ignore_ascii_case(name.trim()))
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.configs
+            .iter()
+            .position(|config| config.name.eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// Location of the user's registry file, e.g. `~/.config/skillcapped-generator/addons.toml`.
+pub fn default_path() -> Option<PathBuf> {
+    settings::config_dir().map(|dir| dir.join(REGISTRY_FILE_NAME))
+}
@@ -1,17 +1,108 @@
+use crate::{
+    addon::{AddonConfig, AddonRegistry},
+    battletag::{self, BattleTag, ValidationIssue},
+    clipboard::{self, ClipboardBackend, ClipboardKind},
+    error::{Error, Result},
+    history::{self, History, HistoryEntry},
+    keymap::Keymap,
+    profile::{
+        Profile, ProfileEdit, ProfileEditKind, ProfilePanel, ProfileStore, PROFILES_FILE_NAME,
+    },
+    settings::Settings,
+    status::StatusMessage,
+    theme::Theme,
+    undo::{EditKind, InputSnapshot, UndoStack},
+    version::GameVersion,
+};
 use base64::{engine::general_purpose, Engine as _};
-use std::time::Instant;
+use std::{
+    cell::RefCell,
+    path::PathBuf,
+    rc::Rc,
+    time::{Instant, SystemTime},
+};
 
-const WA4_CONFIG: &str = "ctdveirvrtdice";
-const WA5_CONFIG: &str = "vridtcetvrdice";
-const DEFAULT_CONFIG: &str = "vridtcetvrdice";
-const MIN_NUMBER_LENGTH: usize = 4;
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Type a Battle.net ID and generate its unlock code.
+    #[default]
+    Generate,
+    /// Paste an existing unlock code and inspect what it was generated for.
+    Verify,
+    /// Pick, add, rename and delete saved profiles.
+    Profiles,
+    /// Browse and re-copy recently copied codes.
+    History,
+    /// Pick one of several BattleTags found in pasted text.
+    PasteSelection,
+}
+
+/// The Battle.net ID and addon config an unlock code was generated for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedCode {
+    /// The ID as encoded, which need not follow the current BattleTag rules:
+    /// older releases accepted other names, and lowercasing can add characters.
+    pub battlenet_id: String,
+    pub config_name: String,
+}
+
+impl DecodedCode {
+    /// True when the decoded ID has no uppercase letters.
+    pub fn is_lowercase(&self) -> bool {
+        self.battlenet_id == battletag::normalize_lowercase(&self.battlenet_id)
+    }
+}
 
+/// What an existing unlock code decodes to, compared with the current Battle.net ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeInspection {
+    pub battlenet_id: String,
+    pub config_name: String,
+    /// True when the decoded ID has no uppercase letters.
+    pub lowercased: bool,
+    pub matches_current_id: bool,
+}
+
+#[derive(Clone)]
 pub struct App {
     pub battlenet_id: String,
+    /// Cursor position in `battlenet_id`, in characters; `None` keeps it at the end.
+    pub cursor: Option<usize>,
+    pub undo: UndoStack,
     pub use_lowercase: bool,
-    pub version: String,
+    pub version: GameVersion,
+    /// Name of the selected addon config; `None` uses the registry default for the version.
+    pub addon: Option<String>,
+    pub mode: Mode,
+    pub verify_input: String,
     pub last_input: Instant,
-    pub copy_feedback: Option<Instant>,
+    /// The last info or error message, shown in the status area until it expires.
+    pub status: Option<StatusMessage>,
+    pub registry: AddonRegistry,
+    /// Where settings are saved when they change; `None` disables saving.
+    pub settings_file: Option<PathBuf>,
+    saved_settings: Settings,
+    /// Settings whose last save failed, so the failure is reported only once.
+    failed_settings: Option<Settings>,
+    pub profiles: ProfileStore,
+    /// Where profiles are saved when they change; `None` disables saving.
+    pub profiles_file: Option<PathBuf>,
+    pub profile_panel: ProfilePanel,
+    pub history: History,
+    /// Where the history is saved when a code is copied; `None` keeps it in memory only.
+    pub history_file: Option<PathBuf>,
+    pub history_selected: usize,
+    pub keymap: Keymap,
+    /// BattleTags found in the last paste, offered when there was more than one.
+    pub paste_candidates: Vec<BattleTag>,
+    pub paste_selected: usize,
+    /// Clipboard backend used for copy and paste.
+    pub clipboard: ClipboardKind,
+    /// Backend used instead of opening `clipboard`, e.g. a [`MemoryClipboard`] in tests.
+    ///
+    /// [`MemoryClipboard`]: crate::clipboard::MemoryClipboard
+    pub clipboard_backend: Option<Rc<RefCell<dyn ClipboardBackend>>>,
+    pub theme: Theme,
 }
 
 impl Default for App {
@@ -22,129 +113,716 @@ impl Default for App {
 
 impl App {
     pub fn new() -> Self {
+        Self::with_registry(AddonRegistry::builtin())
+    }
+
+    pub fn with_registry(registry: AddonRegistry) -> Self {
         Self {
             battlenet_id: String::new(),
+            cursor: None,
+            undo: UndoStack::default(),
             use_lowercase: false,
-            version: "retail".to_string(),
+            version: GameVersion::default(),
+            addon: None,
+            mode: Mode::default(),
+            verify_input: String::new(),
             last_input: Instant::now(),
-            copy_feedback: None,
+            status: None,
+            registry,
+            settings_file: None,
+            saved_settings: Settings::default(),
+            failed_settings: None,
+            profiles: ProfileStore::default(),
+            profiles_file: None,
+            profile_panel: ProfilePanel::default(),
+            history: History::default(),
+            history_file: None,
+            history_selected: 0,
+            keymap: Keymap::default(),
+            paste_candidates: Vec::new(),
+            paste_selected: 0,
+            clipboard: ClipboardKind::default(),
+            clipboard_backend: None,
+            theme: Theme::default(),
+        }
+    }
+
+    /// Starts from `settings`, skipping an addon config that is no longer registered.
+    pub fn apply_settings(&mut self, settings: &Settings) {
+        self.version = settings.version;
+        self.use_lowercase = settings.lowercase;
+        self.addon = settings
+            .addon
+            .clone()
+            .filter(|name| self.registry.get(name).is_some());
+        self.battlenet_id = settings.last_id.clone();
+        self.cursor = None;
+        self.saved_settings = settings.clone();
+    }
+
+    /// The current version, case, addon and Battle.net ID as settings.
+    pub fn settings(&self) -> Settings {
+        Settings {
+            version: self.version,
+            lowercase: self.use_lowercase,
+            addon: self.addon.clone(),
+            last_id: self.battlenet_id.clone(),
+            ..self.saved_settings.clone()
+        }
+    }
+
+    /// Writes the settings to `settings_file` if they changed since the last save.
+    pub fn save_settings(&mut self) -> Result<()> {
+        let settings = self.settings();
+        let Some(path) = &self.settings_file else {
+            return Ok(());
+        };
+        if settings == self.saved_settings {
+            return Ok(());
+        }
+
+        settings.save_to(path)?;
+        self.saved_settings = settings;
+        Ok(())
+    }
+
+    /// Saves changed settings while the UI runs. The Battle.net ID changes with
+    /// every keystroke, so it is only saved by [`App::save_settings`] on quit.
+    /// A failed save is shown in the status area and retried on the next change.
+    pub fn autosave_settings(&mut self) {
+        let settings = Settings {
+            last_id: self.saved_settings.last_id.clone(),
+            ..self.settings()
+        };
+        let Some(path) = &self.settings_file else {
+            return;
+        };
+        if settings == self.saved_settings || self.failed_settings.as_ref() == Some(&settings) {
+            return;
+        }
+
+        match settings.save_to(path) {
+            Ok(()) => {
+                self.saved_settings = settings;
+                self.failed_settings = None;
+            }
+            Err(e) => {
+                self.notify(StatusMessage::error(format!("Settings not saved: {e}")));
+                self.failed_settings = Some(settings);
+            }
         }
     }
 
     pub fn reset_input(&mut self) {
-        self.battlenet_id.clear();
+        self.edit(EditKind::Replace, |app| {
+            app.battlenet_id.clear();
+            app.cursor = None;
+        });
+    }
+
+    /// Replaces the input with the sanitized `text`, e.g. when pasting.
+    pub fn replace_input(&mut self, text: &str) {
+        self.edit(EditKind::Replace, |app| {
+            app.battlenet_id = text.to_string();
+            app.cursor = None;
+            app.sanitize_input();
+        });
+        self.last_input = Instant::now();
+    }
+
+    /// Pastes `text` as the Battle.net ID, keeping only the BattleTag in it.
+    ///
+    /// Text with a single BattleTag replaces the input with it; text with several
+    /// opens a list to pick from; text without one is pasted as-is and sanitized.
+    pub fn paste_battlenet_id(&mut self, text: &str) {
+        let mut tags = battletag::extract(text);
+        match tags.len() {
+            0 => self.replace_input(text),
+            1 => self.replace_input(&tags.remove(0).to_string()),
+            _ => {
+                self.paste_candidates = tags;
+                self.paste_selected = 0;
+                self.mode = Mode::PasteSelection;
+            }
+        }
+    }
+
+    pub fn select_next_paste_candidate(&mut self) {
+        if self.paste_selected + 1 < self.paste_candidates.len() {
+            self.paste_selected += 1;
+        }
+    }
+
+    pub fn select_previous_paste_candidate(&mut self) {
+        self.paste_selected = self.paste_selected.saturating_sub(1);
+    }
+
+    /// Uses the selected BattleTag as the input and closes the list.
+    pub fn use_selected_paste_candidate(&mut self) {
+        if let Some(tag) = self.paste_candidates.get(self.paste_selected) {
+            self.replace_input(&tag.to_string());
+        }
+        self.cancel_paste_selection();
+    }
+
+    /// Closes the BattleTag list without changing the input.
+    pub fn cancel_paste_selection(&mut self) {
+        self.paste_candidates.clear();
+        self.paste_selected = 0;
+        self.mode = Mode::Generate;
+    }
+
+    /// Restores the input as it was before the last edit.
+    pub fn undo(&mut self) {
+        if let Some(snapshot) = self.undo.undo(self.input_snapshot()) {
+            self.restore_input(snapshot);
+        }
+    }
+
+    /// Re-applies the last undone edit.
+    pub fn redo(&mut self) {
+        if let Some(snapshot) = self.undo.redo(self.input_snapshot()) {
+            self.restore_input(snapshot);
+        }
+    }
+
+    fn input_snapshot(&self) -> InputSnapshot {
+        InputSnapshot {
+            text: self.battlenet_id.clone(),
+            cursor: self.cursor,
+        }
+    }
+
+    fn restore_input(&mut self, snapshot: InputSnapshot) {
+        self.battlenet_id = snapshot.text;
+        self.cursor = snapshot.cursor;
+    }
+
+    /// Applies `change` to the input, recording it for undo when the text changed.
+    fn edit(&mut self, kind: EditKind, change: impl FnOnce(&mut Self)) {
+        let before = self.input_snapshot();
+        change(self);
+        if self.battlenet_id != before.text {
+            self.undo.record(kind, before);
+        }
+    }
+
+    pub fn toggle_mode(&mut self) {
+        self.mode = match self.mode {
+            Mode::Verify => Mode::Generate,
+            Mode::Generate | Mode::Profiles | Mode::History | Mode::PasteSelection => Mode::Verify,
+        };
+    }
+
+    pub fn toggle_history(&mut self) {
+        self.mode = match self.mode {
+            Mode::History => Mode::Generate,
+            Mode::Generate | Mode::Verify | Mode::Profiles | Mode::PasteSelection => Mode::History,
+        };
+        self.history_selected = 0;
+    }
+
+    pub fn select_next_history_entry(&mut self) {
+        if self.history_selected + 1 < self.history.len() {
+            self.history_selected += 1;
+        }
+    }
+
+    pub fn select_previous_history_entry(&mut self) {
+        self.history_selected = self.history_selected.saturating_sub(1);
+    }
+
+    /// The code and details for the current Battle.net ID, as recorded in the history.
+    pub fn history_entry(&self) -> Result<HistoryEntry> {
+        Ok(HistoryEntry {
+            code: self.generate_code()?,
+            battlenet_id: self.normalized_id(),
+            version: self.version,
+            config: self.addon_config()?.name.clone(),
+            generated_at: history::unix_seconds(SystemTime::now()),
+        })
+    }
+
+    /// What Ctrl+C copies in the current mode: the generated code, the selected
+    /// profile's code, or the selected history entry.
+    pub fn copy_target(&self) -> Result<HistoryEntry> {
+        match self.mode {
+            Mode::Generate | Mode::Verify | Mode::PasteSelection => self.history_entry(),
+            Mode::Profiles => self.selected_profile_app()?.history_entry(),
+            Mode::History => self
+                .history
+                .get(self.history_selected)
+                .cloned()
+                .ok_or_else(|| Error::InvalidHistory("no history entry selected".to_string())),
+        }
+    }
+
+    /// Runs `f` with `clipboard_backend` if set, otherwise with a newly opened `clipboard` backend.
+    pub fn with_clipboard<T>(
+        &self,
+        f: impl FnOnce(&mut dyn ClipboardBackend) -> Result<T>,
+    ) -> Result<T> {
+        match &self.clipboard_backend {
+            Some(backend) => f(&mut *backend.borrow_mut()),
+            None => f(clipboard::open(self.clipboard)?.as_mut()),
+        }
+    }
+
+    /// Shows `message` in the status area, replacing the previous one.
+    pub fn notify(&mut self, message: StatusMessage) {
+        self.status = Some(message);
+    }
+
+    /// The status message to show at `now`, if it has not expired.
+    pub fn active_status(&self, now: Instant) -> Option<&StatusMessage> {
+        self.status
+            .as_ref()
+            .filter(|message| !message.is_expired(now))
+    }
+
+    /// Adds `entry` to the history and saves it to `history_file`, if set.
+    pub fn record_history(&mut self, entry: HistoryEntry) -> Result<()> {
+        self.history.record(entry);
+        match &self.history_file {
+            Some(path) => self.history.save_to(path),
+            None => Ok(()),
+        }
+    }
+
+    /// Uses `profiles`, reporting profiles skipped while loading them in the status area.
+    pub fn set_profiles(&mut self, profiles: ProfileStore) {
+        if !profiles.skipped().is_empty() {
+            let reasons: Vec<String> = profiles
+                .skipped()
+                .iter()
+                .map(|skipped| skipped.error.to_string())
+                .collect();
+            self.notify(StatusMessage::error(format!(
+                "Skipped profiles in {PROFILES_FILE_NAME}: {}",
+                reasons.join("; ")
+            )));
+        }
+        self.profiles = profiles;
+    }
+
+    pub fn toggle_profiles(&mut self) {
+        self.mode = match self.mode {
+            Mode::Profiles => Mode::Generate,
+            Mode::Generate | Mode::Verify | Mode::History | Mode::PasteSelection => Mode::Profiles,
+        };
+        self.profile_panel.edit = None;
+        self.profile_panel.message = None;
+    }
+
+    pub fn selected_profile(&self) -> Option<&Profile> {
+        self.profiles.get(self.profile_panel.selected)
+    }
+
+    pub fn select_next_profile(&mut self) {
+        if self.profile_panel.selected + 1 < self.profiles.len() {
+            self.profile_panel.selected += 1;
+        }
+    }
+
+    pub fn select_previous_profile(&mut self) {
+        self.profile_panel.selected = self.profile_panel.selected.saturating_sub(1);
+    }
+
+    /// Loads the selected profile into the generator and switches back to it.
+    pub fn use_selected_profile(&mut self) {
+        let Some(profile) = self.selected_profile().cloned() else {
+            return;
+        };
+
+        self.edit(EditKind::Replace, |app| {
+            app.battlenet_id = profile.battle_tag;
+            app.cursor = None;
+        });
+        self.version = profile.version;
+        self.use_lowercase = profile.lowercase;
+        self.mode = Mode::Generate;
+    }
+
+    /// The unlock code for the selected profile, with the current addon config.
+    pub fn selected_profile_code(&self) -> Result<String> {
+        self.selected_profile_app()?.generate_code()
+    }
+
+    fn selected_profile_app(&self) -> Result<App> {
+        let profile = self
+            .selected_profile()
+            .ok_or_else(|| Error::InvalidProfiles("no profile selected".to_string()))?;
+
+        let mut app = self.clone();
+        app.battlenet_id = profile.battle_tag.clone();
+        app.version = profile.version;
+        app.use_lowercase = profile.lowercase;
+        Ok(app)
+    }
+
+    /// Starts typing a label for a new profile from the current Battle.net ID and settings.
+    pub fn start_add_profile(&mut self) {
+        match self.battle_tag() {
+            Ok(battle_tag) => {
+                self.profile_panel.edit = Some(ProfileEdit {
+                    kind: ProfileEditKind::Add,
+                    label: battle_tag.name().to_string(),
+                });
+                self.profile_panel.message = None;
+            }
+            Err(e) => self.profile_panel.message = Some(format!("Cannot add profile: {e}")),
+        }
+    }
+
+    pub fn start_rename_profile(&mut self) {
+        if let Some(profile) = self.selected_profile() {
+            self.profile_panel.edit = Some(ProfileEdit {
+                kind: ProfileEditKind::Rename,
+                label: profile.label.clone(),
+            });
+            self.profile_panel.message = None;
+        }
+    }
+
+    pub fn cancel_profile_edit(&mut self) {
+        self.profile_panel.edit = None;
+    }
+
+    /// Adds or renames the profile with the typed label and saves the profiles.
+    pub fn confirm_profile_edit(&mut self) {
+        let Some(edit) = self.profile_panel.edit.clone() else {
+            return;
+        };
+
+        let result = match edit.kind {
+            ProfileEditKind::Add => self
+                .profiles
+                .add(Profile {
+                    label: edit.label,
+                    battle_tag: battletag::normalize(&self.battlenet_id),
+                    version: self.version,
+                    lowercase: self.use_lowercase,
+                })
+                .map(|()| self.profile_panel.selected = self.profiles.len() - 1),
+            ProfileEditKind::Rename => self
+                .profiles
+                .rename(self.profile_panel.selected, &edit.label),
+        };
+
+        match result.and_then(|()| self.save_profiles()) {
+            Ok(()) => self.profile_panel.edit = None,
+            Err(e) => self.profile_panel.message = Some(e.to_string()),
+        }
+    }
+
+    pub fn delete_selected_profile(&mut self) {
+        let Some(profile) = self.profiles.remove(self.profile_panel.selected) else {
+            return;
+        };
+
+        self.profile_panel.selected = self
+            .profile_panel
+            .selected
+            .min(self.profiles.len().saturating_sub(1));
+        self.profile_panel.message = Some(match self.save_profiles() {
+            Ok(()) => format!("Deleted profile '{}'", profile.label),
+            Err(e) => e.to_string(),
+        });
+    }
+
+    /// Writes the profiles to `profiles_file`, if set.
+    pub fn save_profiles(&self) -> Result<()> {
+        match &self.profiles_file {
+            Some(path) => self.profiles.save_to(path),
+            None => Ok(()),
+        }
     }
 
     pub fn toggle_version(&mut self) {
-        self.version = match self.version.as_str() {
-            "classic" => "retail".to_string(),
-            "retail" => "classic".to_string(),
-            _ => "retail".to_string(),
+        self.version = self.version.next();
+    }
+
+    /// Selects the next registered addon config, wrapping around to the first.
+    pub fn cycle_addon(&mut self) {
+        let configs = self.registry.configs();
+        let current = self
+            .addon_config()
+            .ok()
+            .and_then(|current| configs.iter().position(|config| config == current));
+
+        let next = match current {
+            Some(index) => (index + 1) % configs.len(),
+            None => 0,
         };
+        self.addon = configs.get(next).map(|config| config.name.clone());
+    }
+
+    /// The cursor position in characters, at most the length of the input.
+    pub fn cursor_position(&self) -> usize {
+        let length = self.battlenet_id.chars().count();
+        self.cursor.map_or(length, |cursor| cursor.min(length))
     }
 
+    fn set_cursor(&mut self, position: usize) {
+        let length = self.battlenet_id.chars().count();
+        self.cursor = (position < length).then_some(position);
+    }
+
+    fn byte_index(&self, position: usize) -> usize {
+        self.battlenet_id
+            .char_indices()
+            .nth(position)
+            .map_or(self.battlenet_id.len(), |(index, _)| index)
+    }
+
+    /// Inserts `c` at the cursor.
     pub fn add_char(&mut self, c: char) {
-        self.battlenet_id.push(c);
+        self.edit(EditKind::Insert, |app| {
+            let position = app.cursor_position();
+            app.battlenet_id.insert(app.byte_index(position), c);
+            app.set_cursor(position + 1);
+        });
         self.last_input = Instant::now();
     }
 
+    /// Deletes the character before the cursor (Backspace).
     pub fn remove_char(&mut self) {
-        self.battlenet_id.pop();
+        self.edit(EditKind::Delete, |app| {
+            let position = app.cursor_position();
+            if position == 0 {
+                return;
+            }
+
+            app.battlenet_id.remove(app.byte_index(position - 1));
+            app.set_cursor(position - 1);
+        });
     }
 
-    pub fn sanitize_input(&mut self) {
-        self.battlenet_id
-            .retain(|c| c.is_ascii_alphanumeric() || c == '#');
+    /// Deletes the character under the cursor (Delete).
+    pub fn delete_char(&mut self) {
+        self.edit(EditKind::Delete, |app| {
+            let position = app.cursor_position();
+            if position < app.battlenet_id.chars().count() {
+                app.battlenet_id.remove(app.byte_index(position));
+                app.set_cursor(position);
+            }
+        });
     }
 
-    pub fn is_valid_battlenet_id(&self) -> bool {
-        if self.battlenet_id.is_empty() {
-            return false;
-        }
+    /// Deletes back to the previous `#` or the start of the input (Ctrl+W).
+    pub fn delete_word_before_cursor(&mut self) {
+        self.edit(EditKind::Replace, |app| {
+            let position = app.cursor_position();
+            let before: Vec<char> = app.battlenet_id.chars().take(position).collect();
 
-        let parts: Vec<&str> = self.battlenet_id.split('#').collect();
-        if parts.len() != 2 {
-            return false;
-        }
+            let mut start = position;
+            while start > 0 && before[start - 1] == '#' {
+                start -= 1;
+            }
+            while start > 0 && before[start - 1] != '#' {
+                start -= 1;
+            }
 
-        self.is_valid_name_part(parts[0]) && self.is_valid_number_part(parts[1])
+            let range = app.byte_index(start)..app.byte_index(position);
+            app.battlenet_id.replace_range(range, "");
+            app.set_cursor(start);
+        });
     }
 
-    fn is_valid_name_part(&self, name_part: &str) -> bool {
-        !name_part.is_empty() && name_part.chars().all(|c| c.is_ascii_alphanumeric())
+    /// Deletes everything before the cursor (Ctrl+U).
+    pub fn delete_to_start(&mut self) {
+        self.edit(EditKind::Replace, |app| {
+            let end = app.byte_index(app.cursor_position());
+            app.battlenet_id.replace_range(..end, "");
+            app.set_cursor(0);
+        });
     }
 
-    fn is_valid_number_part(&self, number_part: &str) -> bool {
-        number_part.len() >= MIN_NUMBER_LENGTH && number_part.chars().all(|c| c.is_ascii_digit())
+    pub fn move_cursor_left(&mut self) {
+        self.set_cursor(self.cursor_position().saturating_sub(1));
+        self.undo.break_group();
     }
 
-    pub fn generate_code(&self) -> Result<String, String> {
-        let addon_config = self.get_addon_config()?;
+    pub fn move_cursor_right(&mut self) {
+        self.set_cursor(self.cursor_position() + 1);
+        self.undo.break_group();
+    }
 
-        let mut input = self.battlenet_id.clone();
-        if self.use_lowercase {
-            input = input.to_lowercase();
+    pub fn move_cursor_home(&mut self) {
+        self.set_cursor(0);
+        self.undo.break_group();
+    }
+
+    /// Moves the cursor before the character at `position`, e.g. where the input was clicked.
+    pub fn move_cursor_to(&mut self, position: usize) {
+        self.set_cursor(position);
+        self.undo.break_group();
+    }
+
+    pub fn move_cursor_end(&mut self) {
+        self.cursor = None;
+        self.undo.break_group();
+    }
+
+    /// Normalizes the input and drops characters that cannot be part of a
+    /// BattleTag, keeping the cursor after the same characters.
+    pub fn sanitize_input(&mut self) {
+        let sanitize = |text: &str| {
+            let mut text = battletag::normalize(text);
+            text.retain(|c| battletag::is_name_char(c) || c == '#');
+            text
+        };
+
+        if let Some(cursor) = self.cursor {
+            let prefix: String = self.battlenet_id.chars().take(cursor).collect();
+            self.cursor = Some(sanitize(&prefix).chars().count());
+        }
+        self.battlenet_id = sanitize(&self.battlenet_id);
+        if let Some(cursor) = self.cursor {
+            self.set_cursor(cursor);
+        }
+    }
+
+    pub fn is_valid_battlenet_id(&self) -> bool {
+        self.validation_issues().is_empty()
+    }
+
+    /// Lists every problem with the current Battle.net ID.
+    pub fn validation_issues(&self) -> Vec<ValidationIssue> {
+        battletag::validate(&self.battlenet_id)
+    }
+
+    pub fn battle_tag(&self) -> Result<BattleTag> {
+        self.battlenet_id.parse()
+    }
+
+    /// The Battle.net ID as it is encoded: NFC-normalized, and lowercased when enabled.
+    pub fn normalized_id(&self) -> String {
+        match self.use_lowercase {
+            true => battletag::normalize_lowercase(&self.battlenet_id),
+            false => battletag::normalize(&self.battlenet_id),
         }
-        input.push_str(&addon_config);
+    }
+
+    pub fn generate_code(&self) -> Result<String> {
+        self.battle_tag()?;
+
+        let mut input = self.normalized_id();
+        input.push_str(&self.addon_config()?.suffix);
 
         Ok(general_purpose::STANDARD.encode(input))
     }
 
     pub fn validate_code(&self, encoded_string: &str) -> bool {
-        match self.decode_import_string(encoded_string) {
-            Ok(decoded) => self.matches_expected_format(&decoded),
-            Err(_) => false,
+        self.verify_code(encoded_string).is_ok()
+    }
+
+    /// Checks that `encoded_string` is an unlock code for the current Battle.net ID.
+    pub fn verify_code(&self, encoded_string: &str) -> Result<()> {
+        let decoded = self.decode_import_string(encoded_string)?;
+
+        match self.matches_expected_format(&decoded) {
+            true => Ok(()),
+            false => Err(Error::ConfigMismatch),
+        }
+    }
+
+    /// Decodes `encoded_string` back to the Battle.net ID and addon config it was generated for.
+    ///
+    /// Fails with [`Error::ConfigMismatch`] when the code does not end in a known config suffix.
+    /// The ID before the suffix is returned as-is, without checking the BattleTag rules.
+    pub fn decode_code(&self, encoded_string: &str) -> Result<DecodedCode> {
+        let decoded = battletag::normalize(&self.decode_import_string(encoded_string)?);
+
+        let (config, battlenet_id) = self
+            .registry
+            .configs()
+            .iter()
+            .find_map(|config| {
+                decoded
+                    .strip_suffix(config.suffix.as_str())
+                    .map(|battlenet_id| (config, battlenet_id))
+            })
+            .ok_or(Error::ConfigMismatch)?;
+
+        if battlenet_id.is_empty() {
+            return Err(Error::InvalidBattleTag {
+                reason: "the code contains no Battle.net ID".to_string(),
+            });
         }
+
+        Ok(DecodedCode {
+            battlenet_id: battlenet_id.to_string(),
+            config_name: config.name.clone(),
+        })
+    }
+
+    /// Decodes `encoded_string` and compares it with the current Battle.net ID.
+    pub fn inspect_code(&self, encoded_string: &str) -> Result<CodeInspection> {
+        let decoded = self.decode_code(encoded_string)?;
+        let battlenet_id = decoded.battlenet_id.clone();
+
+        let lowercased = decoded.is_lowercase();
+        let current_id = match lowercased {
+            true => battletag::normalize_lowercase(&self.battlenet_id),
+            false => battletag::normalize(&self.battlenet_id),
+        };
+
+        Ok(CodeInspection {
+            matches_current_id: current_id == battlenet_id,
+            battlenet_id,
+            config_name: decoded.config_name,
+            lowercased,
+        })
     }
 
     fn matches_expected_format(&self, decoded: &str) -> bool {
         let expected_combinations = self.get_expected_combinations();
-        let decoded_lower = decoded.to_lowercase();
+        let decoded = battletag::normalize(decoded);
+        let decoded_lower = battletag::normalize_lowercase(&decoded);
 
         expected_combinations
             .iter()
-            .any(|expected| self.is_matching_combination(expected, decoded, &decoded_lower))
+            .any(|expected| self.is_matching_combination(expected, &decoded, &decoded_lower))
     }
 
     fn is_matching_combination(&self, expected: &str, decoded: &str, decoded_lower: &str) -> bool {
-        decoded_lower == expected.to_lowercase() || decoded == expected
+        decoded_lower == battletag::normalize_lowercase(expected) || decoded == expected
     }
 
     fn get_expected_combinations(&self) -> Vec<String> {
-        let battlenet_lower = self.battlenet_id.to_lowercase();
-        let configs = self.get_wa_config_strings();
-
-        configs
-            .into_iter()
+        let battlenet_id = battletag::normalize(&self.battlenet_id);
+        let battlenet_lower = battletag::normalize_lowercase(&battlenet_id);
+        self.registry
+            .configs()
+            .iter()
+            .map(|config| &config.suffix)
             .flat_map(|config| {
                 vec![
                     format!("{battlenet_lower}{config}"),
-                    format!("{}{config}", self.battlenet_id),
+                    format!("{battlenet_id}{config}"),
                 ]
             })
             .collect()
     }
 
-    fn get_wa_config_strings(&self) -> Vec<&'static str> {
-        vec![WA4_CONFIG, WA5_CONFIG]
-    }
-
-    fn get_addon_config(&self) -> Result<String, String> {
-        match self.version.as_str() {
-            "retail" | "classic" => Ok(DEFAULT_CONFIG.to_string()),
-            _ => Err("Invalid version".to_string()),
+    /// The addon config codes are generated for: the selected one, or the
+    /// registry default for the current version.
+    pub fn addon_config(&self) -> Result<&AddonConfig> {
+        match &self.addon {
+            Some(name) => self
+                .registry
+                .get(name)
+                .ok_or_else(|| Error::UnknownAddon(name.clone())),
+            None => self.registry.default_for(self.version),
         }
     }
 
-    fn decode_import_string(&self, encoded_string: &str) -> Result<String, String> {
+    pub fn decode_import_string(&self, encoded_string: &str) -> Result<String> {
         let cleaned = self.clean_base64_string(encoded_string);
+        let bytes = general_purpose::STANDARD.decode(&cleaned)?;
 
-        general_purpose::STANDARD
-            .decode(&cleaned)
-            .map_err(|e| format!("Base64 decode error: {e}"))
-            .and_then(|bytes| {
-                String::from_utf8(bytes).map_err(|e| format!("UTF-8 decode error: {e}"))
-            })
+        Ok(String::from_utf8(bytes)?)
     }
 
     fn clean_base64_string(&self, input: &str) -> String {
@@ -153,22 +831,4 @@ impl App {
             .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '='))
             .collect()
     }
-
-    pub fn get_wa_configs(&self) -> (String, String) {
-        let wa4 = self.build_wa_config(
-            &[99, 116, 100, 118, 101, 105],
-            &[114, 118, 114, 116, 105, 100, 99, 101],
-        );
-        let wa5 = self.build_wa_config(
-            &[118, 114, 105, 100, 116, 99],
-            &[101, 116, 118, 114, 100, 105, 99, 101],
-        );
-        (wa4, wa5)
-    }
-
-    fn build_wa_config(&self, part1: &[u8], part2: &[u8]) -> String {
-        let part1_str: String = part1.iter().map(|&c| c as char).collect();
-        let part2_str: String = part2.iter().map(|&c| c as char).collect();
-        format!("{part1_str}{part2_str}")
-    }
 }
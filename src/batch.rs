@@ -0,0 +1,74 @@
+use crate::{app::App, output::CodeRecord};
+use std::io::{self, BufRead};
+
+pub struct BatchEntry {
+    pub line_number: usize,
+    pub record: CodeRecord,
+}
+
+impl BatchEntry {
+    pub fn is_valid(&self) -> bool {
+        self.record.is_valid()
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct BatchSummary {
+    pub generated: usize,
+    pub invalid: usize,
+}
+
+impl BatchSummary {
+    pub fn from_entries(entries: &[BatchEntry]) -> Self {
+        let generated = entries.iter().filter(|entry| entry.is_valid()).count();
+        Self {
+            generated,
+            invalid: entries.len() - generated,
+        }
+    }
+}
+
+const BYTE_ORDER_MARK: char = '\u{feff}';
+
+/// Generates an unlock code for every non-blank line of `reader`.
+///
+/// Codes use the version, case and addon config of `generator`. Invalid lines,
+/// including lines that are not valid UTF-8, are reported in their entry
+/// instead of aborting the batch. A byte order mark before the first line is ignored.
+pub fn generate_batch(mut reader: impl BufRead, generator: &App) -> io::Result<Vec<BatchEntry>> {
+    let mut entries = Vec::new();
+    let mut line = Vec::new();
+
+    for line_number in 1.. {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+
+        let record = match std::str::from_utf8(&line) {
+            Ok(text) => {
+                let text = match line_number {
+                    1 => text.trim_start_matches(BYTE_ORDER_MARK),
+                    _ => text,
+                };
+                let battlenet_id = text.trim();
+                if battlenet_id.is_empty() {
+                    continue;
+                }
+                CodeRecord::generate(generator, battlenet_id)
+            }
+            Err(_) => CodeRecord::unreadable(
+                generator,
+                String::from_utf8_lossy(&line).trim(),
+                "line is not valid UTF-8",
+            ),
+        };
+
+        entries.push(BatchEntry {
+            line_number,
+            record,
+        });
+    }
+
+    Ok(entries)
+}
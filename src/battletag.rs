@@ -0,0 +1,228 @@
+use crate::error::Error;
+use std::{fmt, str::FromStr};
+use unicode_normalization::UnicodeNormalization;
+
+pub const MIN_NAME_LENGTH: usize = 3;
+pub const MAX_NAME_LENGTH: usize = 12;
+pub const MIN_NUMBER_LENGTH: usize = 4;
+pub const MAX_NUMBER_LENGTH: usize = 6;
+
+/// A specific reason why a Battle.net ID is not valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationIssue {
+    Empty,
+    MissingHash,
+    MultipleHashes,
+    EmptyName,
+    NameTooShort,
+    NameTooLong,
+    NameStartsWithDigit,
+    InvalidNameCharacter(char),
+    NonDigitDiscriminator,
+    DiscriminatorTooShort,
+    DiscriminatorTooLong,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationIssue::Empty => f.write_str("Battle.net ID is empty"),
+            ValidationIssue::MissingHash => f.write_str("missing '#' between name and number"),
+            ValidationIssue::MultipleHashes => f.write_str("only one '#' is allowed"),
+            ValidationIssue::EmptyName => f.write_str("name before '#' is empty"),
+            ValidationIssue::NameTooShort => {
+                write!(f, "name must be at least {MIN_NAME_LENGTH} characters")
+            }
+            ValidationIssue::NameTooLong => {
+                write!(f, "name must be at most {MAX_NAME_LENGTH} characters")
+            }
+            ValidationIssue::NameStartsWithDigit => {
+                f.write_str("name must not start with a number")
+            }
+            ValidationIssue::InvalidNameCharacter(c) => {
+                write!(f, "name contains invalid character '{c}'")
+            }
+            ValidationIssue::NonDigitDiscriminator => {
+                f.write_str("number after '#' must only contain digits")
+            }
+            ValidationIssue::DiscriminatorTooShort => {
+                write!(
+                    f,
+                    "number after '#' must be at least {MIN_NUMBER_LENGTH} digits"
+                )
+            }
+            ValidationIssue::DiscriminatorTooLong => {
+                write!(
+                    f,
+                    "number after '#' must be at most {MAX_NUMBER_LENGTH} digits"
+                )
+            }
+        }
+    }
+}
+
+/// A Battle.net ID that follows Blizzard's BattleTag naming rules.
+///
+/// The name is 3–12 letters or digits and must not start with a digit. Letters
+/// from any script are allowed, so accented, Cyrillic and Korean names are valid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BattleTag {
+    name: String,
+    number: String,
+}
+
+impl BattleTag {
+    /// Parses `battlenet_id` after NFC normalization, returning every problem when it is not valid.
+    pub fn parse(battlenet_id: &str) -> Result<Self, Vec<ValidationIssue>> {
+        let battlenet_id = normalize(battlenet_id);
+        let issues = validate(&battlenet_id);
+        if !issues.is_empty() {
+            return Err(issues);
+        }
+
+        let (name, number) = battlenet_id
+            .split_once('#')
+            .expect("validated BattleTag contains '#'");
+        Ok(Self {
+            name: name.to_string(),
+            number: number.to_string(),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+}
+
+impl fmt::Display for BattleTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}#{}", self.name, self.number)
+    }
+}
+
+impl FromStr for BattleTag {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).map_err(|issues| Error::InvalidBattleTag {
+            reason: issues[0].to_string(),
+        })
+    }
+}
+
+/// Finds every valid BattleTag in free text such as "Hi, my tag is Foo#1234!".
+///
+/// Each `#` is read together with the letters and digits directly before it and
+/// the digits directly after it; tokens that break the naming rules are skipped.
+/// Tags are returned in the order they appear, without duplicates.
+pub fn extract(text: &str) -> Vec<BattleTag> {
+    let chars: Vec<char> = normalize(text).chars().collect();
+    let mut tags: Vec<BattleTag> = Vec::new();
+
+    for (hash, _) in chars.iter().enumerate().filter(|(_, &c)| c == '#') {
+        let start = chars[..hash]
+            .iter()
+            .rposition(|&c| !is_name_char(c))
+            .map_or(0, |i| i + 1);
+        let end = chars[hash + 1..]
+            .iter()
+            .position(|c| !c.is_ascii_digit())
+            .map_or(chars.len(), |i| hash + 1 + i);
+
+        if chars.get(end).is_some_and(|&c| is_name_char(c)) {
+            continue;
+        }
+
+        let token: String = chars[start..end].iter().collect();
+        if let Ok(tag) = BattleTag::parse(&token) {
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+    }
+
+    tags
+}
+
+/// Normalizes `text` to Unicode NFC so composed and decomposed input encode identically.
+pub fn normalize(text: &str) -> String {
+    text.nfc().collect()
+}
+
+/// Lowercases `text` with Unicode case mapping and normalizes the result to NFC.
+pub fn normalize_lowercase(text: &str) -> String {
+    normalize(text).to_lowercase().nfc().collect()
+}
+
+/// Returns true for characters allowed in the name part of a BattleTag.
+pub fn is_name_char(c: char) -> bool {
+    c.is_alphabetic() || c.is_ascii_digit()
+}
+
+/// Returns every problem with `battlenet_id`, or an empty list when it is valid.
+///
+/// The ID is NFC-normalized first, so decomposed accents are accepted.
+pub fn validate(battlenet_id: &str) -> Vec<ValidationIssue> {
+    let battlenet_id = normalize(battlenet_id);
+    let battlenet_id = battlenet_id.as_str();
+    if battlenet_id.is_empty() {
+        return vec![ValidationIssue::Empty];
+    }
+
+    let Some((name, number)) = battlenet_id.split_once('#') else {
+        let mut issues = vec![ValidationIssue::MissingHash];
+        issues.extend(validate_name(battlenet_id));
+        return issues;
+    };
+
+    let mut issues = validate_name(name);
+    if number.contains('#') {
+        issues.push(ValidationIssue::MultipleHashes);
+    } else {
+        issues.extend(validate_number(number));
+    }
+    issues
+}
+
+fn validate_name(name: &str) -> Vec<ValidationIssue> {
+    let length = name.chars().count();
+    let mut issues = Vec::new();
+
+    match length {
+        0 => issues.push(ValidationIssue::EmptyName),
+        n if n < MIN_NAME_LENGTH => issues.push(ValidationIssue::NameTooShort),
+        n if n > MAX_NAME_LENGTH => issues.push(ValidationIssue::NameTooLong),
+        _ => {}
+    }
+
+    if name.starts_with(|c: char| c.is_numeric()) {
+        issues.push(ValidationIssue::NameStartsWithDigit);
+    }
+
+    if let Some(c) = name.chars().find(|&c| !is_name_char(c)) {
+        issues.push(ValidationIssue::InvalidNameCharacter(c));
+    }
+
+    issues
+}
+
+fn validate_number(number: &str) -> Vec<ValidationIssue> {
+    let length = number.chars().count();
+    let mut issues = Vec::new();
+
+    if !number.chars().all(|c| c.is_ascii_digit()) {
+        issues.push(ValidationIssue::NonDigitDiscriminator);
+    }
+
+    match length {
+        n if n < MIN_NUMBER_LENGTH => issues.push(ValidationIssue::DiscriminatorTooShort),
+        n if n > MAX_NUMBER_LENGTH => issues.push(ValidationIssue::DiscriminatorTooLong),
+        _ => {}
+    }
+
+    issues
+}
@@ -0,0 +1,303 @@
+use crate::{
+    addon::AddonRegistry,
+    app::App,
+    batch::{generate_batch, BatchSummary},
+    clipboard::{self, ClipboardKind},
+    output::{write_decoded, write_record, write_records, CodeRecord, DecodeRecord, OutputFormat},
+    theme::ThemeName,
+    version::GameVersion,
+};
+use clap::{Parser, Subcommand};
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+};
+
+/// Exit code when the unlock code was generated successfully.
+pub const EXIT_SUCCESS: u8 = 0;
+/// Exit code for unexpected failures such as I/O errors.
+pub const EXIT_FAILURE: u8 = 1;
+/// Exit code for invalid command line usage (matches clap's default).
+pub const EXIT_USAGE: u8 = 2;
+/// Exit code when the supplied Battle.net ID (or any batch entry) is not valid.
+pub const EXIT_INVALID_ID: u8 = 3;
+/// Exit code when the code was printed but could not be copied to the clipboard.
+pub const EXIT_CLIPBOARD: u8 = 4;
+/// Exit code when `decode` is given a code that does not decode to a known format.
+pub const EXIT_INVALID_CODE: u8 = 5;
+
+const EXIT_CODES_HELP: &str = "\
+Exit codes:
+  0  Unlock code generated
+  1  Unexpected failure
+  2  Invalid command line usage
+  3  Invalid Battle.net ID (in batch mode: at least one invalid entry)
+  4  Unlock code printed, but copying to the clipboard failed
+  5  Unlock code could not be decoded (decode subcommand)
+
+Without --id, --batch or a subcommand the interactive terminal UI is started.";
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "skillcapped-generator",
+    about = "SkillCapped unlock code generator",
+    disable_version_flag = true,
+    after_help = EXIT_CODES_HELP
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Battle.net ID to generate an unlock code for (format: Name#1234)
+    #[arg(short, long, value_name = "BATTLETAG")]
+    pub id: Option<String>,
+
+    /// Generate codes for newline-separated Battle.net IDs read from FILE ("-" for stdin)
+    #[arg(short, long, value_name = "FILE", conflicts_with_all = ["id", "copy"])]
+    pub batch: Option<PathBuf>,
+
+    /// Game version: retail, classic, classic-era, cata-classic or mop-classic
+    #[arg(
+        short = 'v',
+        long = "version",
+        value_name = "VERSION",
+        default_value_t = GameVersion::Retail
+    )]
+    pub game_version: GameVersion,
+
+    /// Addon config to generate for, e.g. WA4 or WA5 (default: the registry default for the version)
+    #[arg(short, long, value_name = "NAME")]
+    pub addon: Option<String>,
+
+    /// Lowercase the Battle.net ID before encoding
+    #[arg(short, long)]
+    pub lowercase: bool,
+
+    /// Copy the unlock code to the clipboard
+    #[arg(short, long)]
+    pub copy: bool,
+
+    /// Clipboard backend for --copy: auto, system, osc52, wl-copy or xclip
+    #[arg(long, value_enum, value_name = "KIND", default_value_t = ClipboardKind::Auto, requires = "copy")]
+    pub clipboard: ClipboardKind,
+
+    /// Output format for generated or decoded codes
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Plain, global = true)]
+    pub format: OutputFormat,
+
+    /// Suppress informational messages on stderr
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// Color theme of the terminal UI (default: the settings file, or monochrome when NO_COLOR is set)
+    #[arg(long, value_enum, value_name = "THEME")]
+    pub theme: Option<ThemeName>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Decode an unlock code back to the Battle.net ID and addon config it was generated for
+    Decode {
+        /// The unlock code to decode
+        code: String,
+    },
+}
+
+impl Cli {
+    pub fn is_interactive(&self) -> bool {
+        self.command.is_none() && self.id.is_none() && self.batch.is_none()
+    }
+
+    /// Builds the generator for the version, case and addon config selected by the flags.
+    pub fn generator(&self, registry: &AddonRegistry) -> App {
+        let mut app = App::with_registry(registry.clone());
+        app.version = self.game_version;
+        app.use_lowercase = self.lowercase;
+        app.addon = self.addon.clone();
+        app
+    }
+}
+
+/// Runs the non-interactive mode and returns the process exit code.
+///
+/// Addon configs are loaded from the user's registry file, if there is one.
+pub fn run(cli: &Cli, out: &mut impl Write, err: &mut impl Write) -> u8 {
+    match AddonRegistry::load() {
+        Ok(registry) => run_with_registry(cli, &registry, out, err),
+        Err(e) => {
+            let _ = writeln!(err, "Error: {e}");
+            EXIT_FAILURE
+        }
+    }
+}
+
+/// Runs the non-interactive mode with the addon configs in `registry`.
+pub fn run_with_registry(
+    cli: &Cli,
+    registry: &AddonRegistry,
+    out: &mut impl Write,
+    err: &mut impl Write,
+) -> u8 {
+    if let Some(Command::Decode { code }) = &cli.command {
+        return run_decode(cli, registry, code, out, err);
+    }
+
+    if let Err(e) = cli.generator(registry).addon_config() {
+        let available: Vec<&str> = registry
+            .configs()
+            .iter()
+            .map(|config| config.name.as_str())
+            .collect();
+        let _ = writeln!(err, "Error: {e} (available: {})", available.join(", "));
+        return EXIT_USAGE;
+    }
+
+    if let Some(path) = cli.batch.as_deref() {
+        return match open_batch_input(path) {
+            Ok(reader) => run_batch(cli, registry, reader, out, err),
+            Err(e) => {
+                let _ = writeln!(err, "Error: could not read '{}': {e}", path.display());
+                EXIT_FAILURE
+            }
+        };
+    }
+
+    match cli.id.as_deref() {
+        Some(battlenet_id) => run_single(cli, registry, battlenet_id, out, err),
+        None => EXIT_USAGE,
+    }
+}
+
+/// Runs batch mode over `reader`, writing one result per entry.
+pub fn run_batch(
+    cli: &Cli,
+    registry: &AddonRegistry,
+    reader: impl BufRead,
+    out: &mut impl Write,
+    err: &mut impl Write,
+) -> u8 {
+    let entries = match generate_batch(reader, &cli.generator(registry)) {
+        Ok(entries) => entries,
+        Err(e) => {
+            let _ = writeln!(err, "Error: could not read batch input: {e}");
+            return EXIT_FAILURE;
+        }
+    };
+
+    for entry in entries.iter().filter(|entry| !entry.is_valid()) {
+        let _ = writeln!(
+            err,
+            "line {}: '{}': {}",
+            entry.line_number,
+            entry.record.input,
+            entry.record.errors.join("; ")
+        );
+    }
+
+    let records: Vec<CodeRecord> = entries.iter().map(|entry| entry.record.clone()).collect();
+    if write_records(cli.format, &records, out).is_err() {
+        return EXIT_FAILURE;
+    }
+
+    let summary = BatchSummary::from_entries(&entries);
+    if !cli.quiet {
+        let _ = writeln!(
+            err,
+            "Summary: {} generated, {} invalid",
+            summary.generated, summary.invalid
+        );
+    }
+
+    match summary.invalid {
+        0 => EXIT_SUCCESS,
+        _ => EXIT_INVALID_ID,
+    }
+}
+
+/// Runs the `decode` subcommand, writing the BattleTag and config variant of `code`.
+pub fn run_decode(
+    cli: &Cli,
+    registry: &AddonRegistry,
+    code: &str,
+    out: &mut impl Write,
+    err: &mut impl Write,
+) -> u8 {
+    let decoded = match cli.generator(registry).decode_code(code) {
+        Ok(decoded) => decoded,
+        Err(e) => {
+            let _ = writeln!(err, "Error: could not decode unlock code: {e}");
+            return EXIT_INVALID_CODE;
+        }
+    };
+
+    match write_decoded(cli.format, &DecodeRecord::new(code, &decoded), out) {
+        Ok(()) => EXIT_SUCCESS,
+        Err(_) => EXIT_FAILURE,
+    }
+}
+
+fn open_batch_input(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    if path == Path::new("-") {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+
+    Ok(Box::new(BufReader::new(File::open(path)?)))
+}
+
+fn run_single(
+    cli: &Cli,
+    registry: &AddonRegistry,
+    battlenet_id: &str,
+    out: &mut impl Write,
+    err: &mut impl Write,
+) -> u8 {
+    let record = CodeRecord::generate(&cli.generator(registry), battlenet_id);
+
+    if !record.is_valid() {
+        let _ = writeln!(
+            err,
+            "Error: invalid Battle.net ID '{battlenet_id}': {}",
+            record.errors.join("; ")
+        );
+    }
+
+    let written = match (cli.format, &record.code) {
+        (OutputFormat::Plain, Some(code)) => writeln!(out, "{code}"),
+        (OutputFormat::Plain, None) => Ok(()),
+        (format, _) => write_record(format, &record, out),
+    };
+    if written.is_err() {
+        return EXIT_FAILURE;
+    }
+
+    match (&record.code, cli.copy) {
+        (None, _) => EXIT_INVALID_ID,
+        (Some(code), true) => {
+            // The copy may wait for the clipboard to be taken over, so show the code first.
+            let _ = out.flush();
+            copy_to_clipboard(code, cli, err)
+        }
+        (Some(_), false) => EXIT_SUCCESS,
+    }
+}
+
+fn copy_to_clipboard(unlock_code: &str, cli: &Cli, err: &mut impl Write) -> u8 {
+    let copied = clipboard::open_persistent(cli.clipboard).and_then(|mut clipboard| {
+        clipboard.set_text(unlock_code)?;
+        Ok(clipboard.kind())
+    });
+
+    match copied {
+        Ok(kind) => {
+            if !cli.quiet {
+                let _ = writeln!(err, "Unlock code copied to clipboard via {kind}");
+            }
+            EXIT_SUCCESS
+        }
+        Err(e) => {
+            let _ = writeln!(err, "Error: could not copy the unlock code: {e}");
+            EXIT_CLIPBOARD
+        }
+    }
+}
@@ -0,0 +1,347 @@
+use crate::error::{Error, Result};
+use base64::{engine::general_purpose, Engine as _};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::{
+    env, fmt,
+    io::{self, Write},
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+/// How long a persistent system clipboard keeps serving a copy on Linux
+/// before the process exits, unless another program takes it over sooner.
+pub const PERSISTENT_WAIT: Duration = Duration::from_secs(5);
+
+/// Somewhere the unlock code can be copied to and a Battle.net ID pasted from.
+pub trait ClipboardBackend {
+    fn kind(&self) -> ClipboardKind;
+    fn set_text(&mut self, text: &str) -> Result<()>;
+    fn get_text(&mut self) -> Result<String>;
+}
+
+/// Which clipboard backend to use, set with `clipboard = "..."` in `config.toml`
+/// or `--clipboard` on the command line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum ClipboardKind {
+    /// Pick the first backend that works in the current session.
+    #[default]
+    Auto,
+    /// The desktop clipboard, accessed directly.
+    System,
+    /// OSC 52 escape sequences, which the terminal emulator turns into a copy,
+    /// also over SSH. Copy only.
+    Osc52,
+    /// The `wl-copy` and `wl-paste` commands on Wayland.
+    WlCopy,
+    /// The `xclip` command on X11.
+    Xclip,
+}
+
+impl ClipboardKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            ClipboardKind::Auto => "auto",
+            ClipboardKind::System => "system",
+            ClipboardKind::Osc52 => "OSC 52",
+            ClipboardKind::WlCopy => "wl-copy",
+            ClipboardKind::Xclip => "xclip",
+        }
+    }
+
+    /// Resolves `Auto` to a concrete backend for the current session.
+    pub fn resolve(self) -> Self {
+        match self {
+            ClipboardKind::Auto => detect(arboard::Clipboard::new().is_ok(), env_set, has_program),
+            kind => kind,
+        }
+    }
+
+    /// Like [`ClipboardKind::resolve`], but for a process that exits right after copying.
+    pub fn resolve_persistent(self) -> Self {
+        match self {
+            ClipboardKind::Auto if cfg!(target_os = "linux") => {
+                detect_persistent(arboard::Clipboard::new().is_ok(), env_set, has_program)
+            }
+            kind => kind.resolve(),
+        }
+    }
+}
+
+impl fmt::Display for ClipboardKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Picks a backend: the system clipboard when it can be opened, then the
+/// clipboard command for the running display server, then OSC 52.
+pub fn detect(
+    system_available: bool,
+    env_set: impl Fn(&str) -> bool,
+    has_program: impl Fn(&str) -> bool,
+) -> ClipboardKind {
+    if system_available {
+        ClipboardKind::System
+    } else {
+        clipboard_command(&env_set, &has_program).unwrap_or(ClipboardKind::Osc52)
+    }
+}
+
+/// Picks a backend for a process that exits right after copying: `wl-copy` and
+/// `xclip` keep serving the clipboard in the background, so they come before the
+/// system clipboard, which only waits [`PERSISTENT_WAIT`] for someone to take it over.
+pub fn detect_persistent(
+    system_available: bool,
+    env_set: impl Fn(&str) -> bool,
+    has_program: impl Fn(&str) -> bool,
+) -> ClipboardKind {
+    match clipboard_command(&env_set, &has_program) {
+        Some(kind) => kind,
+        None => detect(system_available, env_set, has_program),
+    }
+}
+
+/// The clipboard command for the running display server, if it is installed.
+fn clipboard_command(
+    env_set: &impl Fn(&str) -> bool,
+    has_program: &impl Fn(&str) -> bool,
+) -> Option<ClipboardKind> {
+    if env_set("WAYLAND_DISPLAY") && has_program("wl-copy") {
+        Some(ClipboardKind::WlCopy)
+    } else if env_set("DISPLAY") && has_program("xclip") {
+        Some(ClipboardKind::Xclip)
+    } else {
+        None
+    }
+}
+
+/// Opens the backend for `kind`, resolving `Auto` first.
+pub fn open(kind: ClipboardKind) -> Result<Box<dyn ClipboardBackend>> {
+    Ok(match kind.resolve() {
+        ClipboardKind::Auto | ClipboardKind::System => Box::new(SystemClipboard::new()?),
+        ClipboardKind::Osc52 => Box::new(Osc52Clipboard::new(io::stdout())),
+        ClipboardKind::WlCopy => Box::new(CommandClipboard::wl_copy()),
+        ClipboardKind::Xclip => Box::new(CommandClipboard::xclip()),
+    })
+}
+
+/// Like [`open`], but for a process that exits right after copying: `auto`
+/// prefers the clipboard commands on Linux, the system clipboard keeps the text
+/// available for a while, and OSC 52 goes to stderr since stdout carries the output.
+pub fn open_persistent(kind: ClipboardKind) -> Result<Box<dyn ClipboardBackend>> {
+    Ok(match kind.resolve_persistent() {
+        ClipboardKind::Auto | ClipboardKind::System => Box::new(SystemClipboard::persistent()?),
+        ClipboardKind::Osc52 => Box::new(Osc52Clipboard::new(io::stderr())),
+        kind => open(kind)?,
+    })
+}
+
+/// Keeps the text in memory instead of a real clipboard, e.g. in tests.
+#[derive(Debug, Default)]
+pub struct MemoryClipboard {
+    pub text: Option<String>,
+}
+
+impl ClipboardBackend for MemoryClipboard {
+    fn kind(&self) -> ClipboardKind {
+        ClipboardKind::Auto
+    }
+
+    fn set_text(&mut self, text: &str) -> Result<()> {
+        self.text = Some(text.to_string());
+        Ok(())
+    }
+
+    fn get_text(&mut self) -> Result<String> {
+        self.text
+            .clone()
+            .ok_or_else(|| Error::Clipboard("the clipboard is empty".to_string()))
+    }
+}
+
+fn env_set(name: &str) -> bool {
+    env::var_os(name).is_some_and(|value| !value.is_empty())
+}
+
+fn has_program(name: &str) -> bool {
+    env::var_os("PATH")
+        .is_some_and(|path| env::split_paths(&path).any(|dir| dir.join(name).is_file()))
+}
+
+/// The desktop clipboard through `arboard`.
+pub struct SystemClipboard {
+    clipboard: arboard::Clipboard,
+    persistent: bool,
+}
+
+impl SystemClipboard {
+    pub fn new() -> Result<Self> {
+        let clipboard = arboard::Clipboard::new().map_err(|e| Error::Clipboard(e.to_string()))?;
+        Ok(Self {
+            clipboard,
+            persistent: false,
+        })
+    }
+
+    /// A clipboard whose copies stay available after the process exits.
+    ///
+    /// On Linux the program that set the clipboard serves it to others, so
+    /// copying waits until a clipboard manager or another program takes it
+    /// over, but no longer than [`PERSISTENT_WAIT`].
+    pub fn persistent() -> Result<Self> {
+        Ok(Self {
+            persistent: true,
+            ..Self::new()?
+        })
+    }
+
+    #[cfg(target_os = "linux")]
+    fn set_persistent_text(&mut self, text: &str) -> std::result::Result<(), arboard::Error> {
+        use arboard::SetExtLinux;
+        use std::time::Instant;
+
+        self.clipboard
+            .set()
+            .wait_until(Instant::now() + PERSISTENT_WAIT)
+            .text(text)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn set_persistent_text(&mut self, text: &str) -> std::result::Result<(), arboard::Error> {
+        self.clipboard.set_text(text)
+    }
+}
+
+impl ClipboardBackend for SystemClipboard {
+    fn kind(&self) -> ClipboardKind {
+        ClipboardKind::System
+    }
+
+    fn set_text(&mut self, text: &str) -> Result<()> {
+        let result = match self.persistent {
+            true => self.set_persistent_text(text),
+            false => self.clipboard.set_text(text),
+        };
+        result.map_err(|e| Error::Clipboard(e.to_string()))
+    }
+
+    fn get_text(&mut self) -> Result<String> {
+        self.clipboard
+            .get_text()
+            .map_err(|e| Error::Clipboard(e.to_string()))
+    }
+}
+
+/// Copies by writing an OSC 52 escape sequence to the terminal.
+pub struct Osc52Clipboard<W: Write> {
+    terminal: W,
+}
+
+impl<W: Write> Osc52Clipboard<W> {
+    pub fn new(terminal: W) -> Self {
+        Self { terminal }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.terminal
+    }
+}
+
+/// The escape sequence that asks the terminal to put `text` on the clipboard.
+pub fn osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", general_purpose::STANDARD.encode(text))
+}
+
+impl<W: Write> ClipboardBackend for Osc52Clipboard<W> {
+    fn kind(&self) -> ClipboardKind {
+        ClipboardKind::Osc52
+    }
+
+    fn set_text(&mut self, text: &str) -> Result<()> {
+        self.terminal
+            .write_all(osc52_sequence(text).as_bytes())
+            .and_then(|()| self.terminal.flush())
+            .map_err(|e| Error::Clipboard(e.to_string()))
+    }
+
+    fn get_text(&mut self) -> Result<String> {
+        Err(Error::Clipboard(
+            "OSC 52 cannot read the clipboard; paste with the terminal instead".to_string(),
+        ))
+    }
+}
+
+/// Copies and pastes through external commands such as `wl-copy` or `xclip`.
+pub struct CommandClipboard {
+    kind: ClipboardKind,
+    copy: (&'static str, &'static [&'static str]),
+    paste: (&'static str, &'static [&'static str]),
+}
+
+impl CommandClipboard {
+    pub fn wl_copy() -> Self {
+        Self {
+            kind: ClipboardKind::WlCopy,
+            copy: ("wl-copy", &[]),
+            paste: ("wl-paste", &["--no-newline"]),
+        }
+    }
+
+    pub fn xclip() -> Self {
+        Self {
+            kind: ClipboardKind::Xclip,
+            copy: ("xclip", &["-selection", "clipboard"]),
+            paste: ("xclip", &["-selection", "clipboard", "-o"]),
+        }
+    }
+}
+
+fn command_error(program: &str, e: impl fmt::Display) -> Error {
+    Error::Clipboard(format!("{program}: {e}"))
+}
+
+impl ClipboardBackend for CommandClipboard {
+    fn kind(&self) -> ClipboardKind {
+        self.kind
+    }
+
+    fn set_text(&mut self, text: &str) -> Result<()> {
+        let (program, args) = self.copy;
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| command_error(program, e))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(text.as_bytes())
+                .map_err(|e| command_error(program, e))?;
+        }
+
+        let status = child.wait().map_err(|e| command_error(program, e))?;
+        match status.success() {
+            true => Ok(()),
+            false => Err(command_error(program, status)),
+        }
+    }
+
+    fn get_text(&mut self) -> Result<String> {
+        let (program, args) = self.paste;
+        let output = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+            .map_err(|e| command_error(program, e))?;
+
+        if !output.status.success() {
+            return Err(command_error(program, output.status));
+        }
+        String::from_utf8(output.stdout).map_err(Error::from)
+    }
+}
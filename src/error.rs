@@ -0,0 +1,86 @@
+use crate::version::GameVersion;
+use std::{fmt, string::FromUtf8Error};
+
+/// Errors returned by the library API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The game version string is not one of the supported versions.
+    InvalidVersion(String),
+    /// The Battle.net ID is not a valid BattleTag.
+    InvalidBattleTag { reason: String },
+    /// The unlock code is not valid base64.
+    Base64(base64::DecodeError),
+    /// The decoded unlock code is not valid UTF-8.
+    Utf8(FromUtf8Error),
+    /// The decoded unlock code does not match any known addon configuration.
+    ConfigMismatch,
+    /// The addon registry file could not be read or parsed.
+    InvalidRegistry(String),
+    /// No registered addon configuration applies to the game version.
+    NoAddonConfig(GameVersion),
+    /// The selected addon config is not in the registry.
+    UnknownAddon(String),
+    /// The settings file could not be read, parsed or written.
+    InvalidSettings(String),
+    /// The profile store could not be read, parsed or written, or a profile is invalid.
+    InvalidProfiles(String),
+    /// Another profile already uses this label.
+    DuplicateProfile(String),
+    /// The saved history could not be read, parsed or written.
+    InvalidHistory(String),
+    /// A key binding in the settings file could not be parsed.
+    InvalidKeyBinding(String),
+    /// The clipboard backend could not copy or paste.
+    Clipboard(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidVersion(version) => write!(f, "invalid version: '{version}'"),
+            Error::InvalidBattleTag { reason } => write!(f, "invalid Battle.net ID: {reason}"),
+            Error::Base64(e) => write!(f, "base64 decode error: {e}"),
+            Error::Utf8(e) => write!(f, "UTF-8 decode error: {e}"),
+            Error::ConfigMismatch => {
+                f.write_str("unlock code does not match any known addon configuration")
+            }
+            Error::InvalidRegistry(reason) => write!(f, "invalid addon registry: {reason}"),
+            Error::NoAddonConfig(version) => {
+                write!(f, "no addon configuration registered for {version}")
+            }
+            Error::UnknownAddon(name) => write!(f, "unknown addon configuration: '{name}'"),
+            Error::InvalidSettings(reason) => write!(f, "invalid settings file: {reason}"),
+            Error::InvalidProfiles(reason) => write!(f, "invalid profiles: {reason}"),
+            Error::DuplicateProfile(label) => {
+                write!(f, "a profile named '{label}' already exists")
+            }
+            Error::InvalidHistory(reason) => write!(f, "invalid history: {reason}"),
+            Error::InvalidKeyBinding(key) => write!(f, "invalid key binding: '{key}'"),
+            Error::Clipboard(reason) => write!(f, "clipboard error: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Base64(e) => Some(e),
+            Error::Utf8(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<base64::DecodeError> for Error {
+    fn from(e: base64::DecodeError) -> Self {
+        Error::Base64(e)
+    }
+}
+
+impl From<FromUtf8Error> for Error {
+    fn from(e: FromUtf8Error) -> Self {
+        Error::Utf8(e)
+    }
+}
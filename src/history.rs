@@ -0,0 +1,142 @@
+use crate::{
+    error::{Error, Result},
+    settings,
+    version::GameVersion,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// File name of the saved history inside the config directory.
+pub const HISTORY_FILE_NAME: &str = "history.toml";
+
+/// Number of entries kept; older ones are dropped.
+pub const MAX_HISTORY: usize = 50;
+
+/// An unlock code that was copied, with what it was generated for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub code: String,
+    pub battlenet_id: String,
+    pub version: GameVersion,
+    pub config: String,
+    /// Seconds since the Unix epoch.
+    pub generated_at: u64,
+}
+
+impl HistoryEntry {
+    pub fn generated_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.generated_at)
+    }
+
+    /// How long before `now` the entry was generated, e.g. "5m ago".
+    pub fn age_label(&self, now: SystemTime) -> String {
+        let seconds = now
+            .duration_since(self.generated_time())
+            .unwrap_or_default()
+            .as_secs();
+
+        match seconds {
+            0..=9 => "just now".to_string(),
+            10..=59 => format!("{seconds}s ago"),
+            60..=3599 => format!("{}m ago", seconds / 60),
+            3600..=86399 => format!("{}h ago", seconds / 3600),
+            _ => format!("{}d ago", seconds / 86400),
+        }
+    }
+}
+
+/// Seconds since the Unix epoch for `time`.
+pub fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct HistoryFile {
+    #[serde(default, rename = "entry")]
+    entries: Vec<HistoryEntry>,
+}
+
+/// Recently copied codes, newest first, bounded to [`MAX_HISTORY`] entries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct History {
+    entries: VecDeque<HistoryEntry>,
+}
+
+impl History {
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let text = fs::read_to_string(path).map_err(|e| {
+            Error::InvalidHistory(format!("could not read '{}': {e}", path.display()))
+        })?;
+        Self::from_toml(&text)
+    }
+
+    pub fn from_toml(text: &str) -> Result<Self> {
+        let file: HistoryFile =
+            toml::from_str(text).map_err(|e| Error::InvalidHistory(e.message().to_string()))?;
+
+        let mut entries: VecDeque<HistoryEntry> = file.entries.into();
+        entries.truncate(MAX_HISTORY);
+        Ok(Self { entries })
+    }
+
+    pub fn to_toml(&self) -> String {
+        let file = HistoryFile {
+            entries: self.entries.iter().cloned().collect(),
+        };
+        toml::to_string(&file).expect("history serializes to TOML")
+    }
+
+    /// Writes the history to `path`, creating its directory if needed.
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        let write = || {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, self.to_toml())
+        };
+
+        write().map_err(|e| {
+            Error::InvalidHistory(format!("could not write '{}': {e}", path.display()))
+        })
+    }
+
+    /// Adds `entry` as the newest entry. Copying the same code again moves it to the top.
+    pub fn record(&mut self, entry: HistoryEntry) {
+        self.entries.retain(|existing| existing.code != entry.code);
+        self.entries.push_front(entry);
+        self.entries.truncate(MAX_HISTORY);
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &HistoryEntry> {
+        self.entries.iter()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&HistoryEntry> {
+        self.entries.get(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Location of the saved history, e.g. `~/.config/skillcapped-generator/history.toml`.
+pub fn default_path() -> Option<PathBuf> {
+    settings::config_dir().map(|dir| dir.join(HISTORY_FILE_NAME))
+}
@@ -1,103 +1,227 @@
-use crate::app::App;
-use arboard::Clipboard;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use std::time::Instant;
+use crate::{
+    app::{App, Mode},
+    keymap::Action,
+    status::StatusMessage,
+    ui::{self, ClickTarget},
+};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
 
 pub fn handle_key_event(app: &mut App, key: KeyEvent) -> bool {
-    if handle_control_keys(app, &key) {
-        return true; // Quit signal
+    if let Some(action) = app.keymap.action_for(&key) {
+        if action.is_global() || app.mode == Mode::Generate {
+            return handle_action(app, action);
+        }
     }
 
-    handle_regular_keys(app, &key);
+    match app.mode {
+        Mode::Generate => handle_regular_keys(app, &key),
+        Mode::Verify => handle_verify_keys(app, &key),
+        Mode::Profiles => handle_profile_keys(app, &key),
+        Mode::History => handle_history_keys(app, &key),
+        Mode::PasteSelection => handle_paste_selection_keys(app, &key),
+    }
     false // Continue running
 }
 
-fn handle_control_keys(app: &mut App, key: &KeyEvent) -> bool {
-    if !key.modifiers.contains(KeyModifiers::CONTROL) {
-        return false;
+/// Handles a click on a screen of size `area`: selects a version or addon
+/// config, copies the code, opens the link or moves the input cursor.
+pub fn handle_mouse_event(app: &mut App, mouse: MouseEvent, area: Rect) {
+    if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+        return;
     }
 
-    match key.code {
-        KeyCode::Char('c') => {
-            handle_copy_code(app);
-            false
-        }
-        KeyCode::Char('v') => {
-            handle_paste_battlenet_id(app);
-            false
-        }
-        KeyCode::Char('g') => {
-            open_github_link();
-            false
-        }
-        KeyCode::Char('q') => true, // Signal to quit
-        _ => false,
+    match ui::click_target(app, area, mouse.column, mouse.row) {
+        Some(ClickTarget::Input(position)) => app.move_cursor_to(position),
+        Some(ClickTarget::Version(version)) => app.version = version,
+        Some(ClickTarget::Addon(name)) => app.addon = Some(name),
+        Some(ClickTarget::Code) => handle_copy_code(app),
+        Some(ClickTarget::Link) => open_github_link(app),
+        None => {}
     }
 }
 
+/// Runs a bound action, returning true when the app should quit.
+fn handle_action(app: &mut App, action: Action) -> bool {
+    match action {
+        Action::Copy => handle_copy_code(app),
+        Action::Paste => handle_paste_battlenet_id(app),
+        Action::Quit => return true,
+        Action::ToggleVersion => app.toggle_version(),
+        Action::CycleAddon => app.cycle_addon(),
+        Action::ToggleCase => app.use_lowercase = !app.use_lowercase,
+        Action::Clear => app.reset_input(),
+        Action::Undo => app.undo(),
+        Action::Redo => app.redo(),
+        Action::OpenLink => open_github_link(app),
+        Action::ToggleDecode => app.toggle_mode(),
+        Action::Profiles => app.toggle_profiles(),
+        Action::History => app.toggle_history(),
+    }
+    false
+}
+
 fn handle_regular_keys(app: &mut App, key: &KeyEvent) {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
     match key.code {
+        KeyCode::Char('w') if ctrl => app.delete_word_before_cursor(),
+        KeyCode::Char('u') if ctrl => app.delete_to_start(),
         KeyCode::Char(c) if !has_modifiers(key) => app.add_char(c),
         KeyCode::Backspace => app.remove_char(),
-        KeyCode::Tab => app.toggle_version(),
-        KeyCode::Enter => app.use_lowercase = !app.use_lowercase,
-        KeyCode::Esc => app.reset_input(),
+        KeyCode::Delete => app.delete_char(),
+        KeyCode::Left => app.move_cursor_left(),
+        KeyCode::Right => app.move_cursor_right(),
+        KeyCode::Home => app.move_cursor_home(),
+        KeyCode::End => app.move_cursor_end(),
         _ => {}
     }
 }
 
-fn has_modifiers(key: &KeyEvent) -> bool {
-    key.modifiers.contains(KeyModifiers::CONTROL)
-        || key.modifiers.contains(KeyModifiers::ALT)
-        || key.modifiers.contains(KeyModifiers::SUPER)
+fn handle_verify_keys(app: &mut App, key: &KeyEvent) {
+    match key.code {
+        KeyCode::Char(c) if !has_modifiers(key) => app.verify_input.push(c),
+        KeyCode::Backspace => {
+            app.verify_input.pop();
+        }
+        KeyCode::Esc => app.verify_input.clear(),
+        _ => {}
+    }
 }
 
-fn handle_copy_code(app: &mut App) {
-    if !app.is_valid_battlenet_id() {
+fn handle_profile_keys(app: &mut App, key: &KeyEvent) {
+    if app.profile_panel.edit.is_some() {
+        handle_profile_edit_keys(app, key);
         return;
     }
 
-    let Ok(unlock_code) = app.generate_code() else {
-        return;
-    };
+    match key.code {
+        KeyCode::Up => app.select_previous_profile(),
+        KeyCode::Down => app.select_next_profile(),
+        KeyCode::Enter => app.use_selected_profile(),
+        KeyCode::Char('a') if !has_modifiers(key) => app.start_add_profile(),
+        KeyCode::Char('r') if !has_modifiers(key) => app.start_rename_profile(),
+        KeyCode::Char('d') if !has_modifiers(key) => app.delete_selected_profile(),
+        KeyCode::Delete => app.delete_selected_profile(),
+        KeyCode::Esc => app.toggle_profiles(),
+        _ => {}
+    }
+}
 
-    let Ok(mut clipboard) = Clipboard::new() else {
+fn handle_profile_edit_keys(app: &mut App, key: &KeyEvent) {
+    let Some(edit) = app.profile_panel.edit.as_mut() else {
         return;
     };
 
-    if clipboard.set_text(unlock_code).is_ok() {
-        app.copy_feedback = Some(Instant::now());
+    match key.code {
+        KeyCode::Char(c) if !has_modifiers(key) => edit.label.push(c),
+        KeyCode::Backspace => {
+            edit.label.pop();
+        }
+        KeyCode::Enter => app.confirm_profile_edit(),
+        KeyCode::Esc => app.cancel_profile_edit(),
+        _ => {}
     }
 }
 
-fn handle_paste_battlenet_id(app: &mut App) {
-    let Ok(mut clipboard) = Clipboard::new() else {
-        return;
-    };
+fn handle_history_keys(app: &mut App, key: &KeyEvent) {
+    match key.code {
+        KeyCode::Up => app.select_previous_history_entry(),
+        KeyCode::Down => app.select_next_history_entry(),
+        KeyCode::Enter => handle_copy_code(app),
+        KeyCode::Esc => app.toggle_history(),
+        _ => {}
+    }
+}
 
-    let Ok(clipboard_text) = clipboard.get_text() else {
-        return;
+fn handle_paste_selection_keys(app: &mut App, key: &KeyEvent) {
+    match key.code {
+        KeyCode::Up => app.select_previous_paste_candidate(),
+        KeyCode::Down => app.select_next_paste_candidate(),
+        KeyCode::Enter => app.use_selected_paste_candidate(),
+        KeyCode::Esc => app.cancel_paste_selection(),
+        _ => {}
+    }
+}
+
+fn has_modifiers(key: &KeyEvent) -> bool {
+    key.modifiers.contains(KeyModifiers::CONTROL)
+        || key.modifiers.contains(KeyModifiers::ALT)
+        || key.modifiers.contains(KeyModifiers::SUPER)
+}
+
+fn handle_copy_code(app: &mut App) {
+    let entry = match app.copy_target() {
+        Ok(entry) => entry,
+        Err(e) => {
+            app.notify(StatusMessage::error(format!("Nothing to copy: {e}")));
+            return;
+        }
     };
 
-    // Clear current input and set to clipboard content
-    app.reset_input();
+    let copied = app.with_clipboard(|clipboard| {
+        clipboard.set_text(&entry.code)?;
+        Ok(clipboard.kind())
+    });
+    match copied {
+        Ok(kind) => app.notify(StatusMessage::info(format!(
+            "Copied to clipboard via {kind}"
+        ))),
+        Err(e) => {
+            app.notify(StatusMessage::error(format!("Copy failed: {e}")));
+            return;
+        }
+    }
+
+    // Re-copying from the history keeps the list order stable.
+    if app.mode != Mode::History {
+        if let Err(e) = app.record_history(entry) {
+            app.notify(StatusMessage::error(format!("Copied, but {e}")));
+        }
+    }
+}
+
+fn handle_paste_battlenet_id(app: &mut App) {
+    if app.mode == Mode::History {
+        return;
+    }
 
-    // Add each character from clipboard
-    for c in clipboard_text.chars() {
-        app.add_char(c);
+    let pasted = app.with_clipboard(|clipboard| clipboard.get_text());
+    match pasted {
+        Ok(text) => handle_paste_event(app, &text),
+        Err(e) => app.notify(StatusMessage::error(format!("Paste failed: {e}"))),
     }
+}
 
-    // Sanitize the pasted input
-    app.sanitize_input();
+/// Handles pasted text, from the clipboard or a terminal paste, as a single edit.
+pub fn handle_paste_event(app: &mut App, text: &str) {
+    match app.mode {
+        Mode::Verify => app.verify_input = text.trim().to_string(),
+        Mode::Profiles => {
+            if let Some(edit) = app.profile_panel.edit.as_mut() {
+                edit.label.push_str(text.trim());
+            }
+        }
+        Mode::History => {}
+        // Keep only the BattleTag when the text holds a whole message
+        Mode::Generate | Mode::PasteSelection => app.paste_battlenet_id(text),
+    }
 }
 
-fn open_github_link() {
+fn open_github_link(app: &mut App) {
     let url = "https://github.com/Xerrion";
 
-    let command_result = get_platform_open_command(url);
-    if let Some((program, args)) = command_result {
-        let _ = std::process::Command::new(program).args(args).spawn();
-    }
+    let status = match get_platform_open_command(url) {
+        Some((program, args)) => match std::process::Command::new(program).args(args).spawn() {
+            Ok(_) => StatusMessage::info(format!("Opening {url}")),
+            Err(e) => StatusMessage::error(format!("Could not open {url}: {program}: {e}")),
+        },
+        None => StatusMessage::error(format!(
+            "Opening links is not supported on {}; visit {url}",
+            std::env::consts::OS
+        )),
+    };
+    app.notify(status);
 }
 
 fn get_platform_open_command(url: &str) -> Option<(&'static str, Vec<&str>)> {
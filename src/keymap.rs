@@ -0,0 +1,265 @@
+use crate::error::{Error, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+/// Something the user can trigger with a key binding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Action {
+    Copy,
+    Paste,
+    Quit,
+    ToggleVersion,
+    CycleAddon,
+    ToggleCase,
+    Clear,
+    Undo,
+    Redo,
+    OpenLink,
+    ToggleDecode,
+    Profiles,
+    History,
+}
+
+impl Action {
+    /// Actions that edit the generator's settings or input only apply in generate mode,
+    /// so their keys stay free for the other panels.
+    pub fn is_global(self) -> bool {
+        !matches!(
+            self,
+            Action::ToggleVersion
+                | Action::CycleAddon
+                | Action::ToggleCase
+                | Action::Clear
+                | Action::Undo
+                | Action::Redo
+        )
+    }
+}
+
+/// A key together with the modifiers that must be held, e.g. `Ctrl+C`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    const fn plain(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+
+    const fn ctrl(c: char) -> Self {
+        Self::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        normalize(self.code, self.modifiers) == normalize(key.code, key.modifiers)
+    }
+}
+
+/// Folds the different ways terminals report the same key: letters are
+/// compared case-insensitively and Shift is implied by `BackTab` and by
+/// shifted characters.
+fn normalize(code: KeyCode, modifiers: KeyModifiers) -> (KeyCode, KeyModifiers) {
+    match code {
+        KeyCode::Char(c) => (
+            KeyCode::Char(c.to_ascii_lowercase()),
+            modifiers - KeyModifiers::SHIFT,
+        ),
+        KeyCode::BackTab => (code, modifiers - KeyModifiers::SHIFT),
+        _ => (code, modifiers),
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (KeyModifiers::CONTROL, "Ctrl+"),
+            (KeyModifiers::ALT, "Alt+"),
+            (KeyModifiers::SUPER, "Super+"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                f.write_str(name)?;
+            }
+        }
+
+        match self.code {
+            KeyCode::BackTab => f.write_str("Shift+Tab"),
+            KeyCode::Char(' ') => f.write_str("Space"),
+            KeyCode::Char(c) if self.modifiers.is_empty() => write!(f, "{c}"),
+            KeyCode::Char(c) => write!(f, "{}", c.to_ascii_uppercase()),
+            KeyCode::F(n) => write!(f, "F{n}"),
+            KeyCode::Esc => f.write_str("Esc"),
+            KeyCode::PageUp => f.write_str("PageUp"),
+            KeyCode::PageDown => f.write_str("PageDown"),
+            code => write!(f, "{code:?}"),
+        }
+    }
+}
+
+impl FromStr for KeyBinding {
+    type Err = Error;
+
+    /// Parses bindings such as `ctrl+c`, `alt+shift+l`, `f5`, `enter` or `shift+tab`.
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::InvalidKeyBinding(s.to_string());
+        let lowercase = s.trim().to_ascii_lowercase();
+        let mut parts: Vec<&str> = lowercase.split('+').collect();
+        let key = parts
+            .pop()
+            .filter(|key| !key.is_empty())
+            .ok_or_else(invalid)?;
+
+        let mut modifiers = KeyModifiers::NONE;
+        for part in parts {
+            modifiers |= match part {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                "super" | "cmd" => KeyModifiers::SUPER,
+                _ => return Err(invalid()),
+            };
+        }
+
+        let code = match key {
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "space" => KeyCode::Char(' '),
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" | "ins" => KeyCode::Insert,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            _ => match key.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                Some(n @ 1..=24) => KeyCode::F(n),
+                _ => {
+                    let mut chars = key.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => KeyCode::Char(c),
+                        _ => return Err(invalid()),
+                    }
+                }
+            },
+        };
+
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+/// One key or a list of keys for an action in the `[keys]` table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeySpec {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeySpec {
+    fn keys(&self) -> Vec<&str> {
+        match self {
+            KeySpec::One(key) => vec![key.as_str()],
+            KeySpec::Many(keys) => keys.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+/// The active key bindings, checked in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: Vec<(KeyBinding, Action)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self {
+            bindings: vec![
+                (KeyBinding::ctrl('c'), Action::Copy),
+                (KeyBinding::ctrl('v'), Action::Paste),
+                (KeyBinding::ctrl('q'), Action::Quit),
+                (KeyBinding::plain(KeyCode::Tab), Action::ToggleVersion),
+                (
+                    KeyBinding::new(KeyCode::BackTab, KeyModifiers::SHIFT),
+                    Action::CycleAddon,
+                ),
+                (KeyBinding::plain(KeyCode::Enter), Action::ToggleCase),
+                (KeyBinding::plain(KeyCode::Esc), Action::Clear),
+                (KeyBinding::ctrl('z'), Action::Undo),
+                (KeyBinding::ctrl('y'), Action::Redo),
+                (KeyBinding::ctrl('g'), Action::OpenLink),
+                (KeyBinding::ctrl('d'), Action::ToggleDecode),
+                (KeyBinding::ctrl('p'), Action::Profiles),
+                (KeyBinding::ctrl('r'), Action::History),
+            ],
+        }
+    }
+}
+
+impl Keymap {
+    /// The default bindings with the actions in `overrides` rebound.
+    ///
+    /// A rebound action loses its default keys, and its new keys are taken
+    /// away from any other action that used them.
+    pub fn with_overrides(overrides: &BTreeMap<Action, KeySpec>) -> Result<Self> {
+        let mut keymap = Self::default();
+
+        for (&action, spec) in overrides {
+            let keys = spec
+                .keys()
+                .into_iter()
+                .map(str::parse)
+                .collect::<Result<Vec<KeyBinding>>>()?;
+            keymap.bind(action, &keys);
+        }
+
+        Ok(keymap)
+    }
+
+    /// Replaces the keys of `action` with `keys`.
+    pub fn bind(&mut self, action: Action, keys: &[KeyBinding]) {
+        self.bindings.retain(|(binding, bound)| {
+            *bound != action && !keys.iter().any(|key| key.matches(&to_event(binding)))
+        });
+        self.bindings
+            .extend(keys.iter().map(|&binding| (binding, action)));
+    }
+
+    pub fn action_for(&self, key: &KeyEvent) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(binding, _)| binding.matches(key))
+            .map(|&(_, action)| action)
+    }
+
+    pub fn keys_for(&self, action: Action) -> impl Iterator<Item = KeyBinding> + '_ {
+        self.bindings
+            .iter()
+            .filter(move |(_, bound)| *bound == action)
+            .map(|&(binding, _)| binding)
+    }
+
+    /// The first key bound to `action` for display, e.g. "Ctrl+C", or "unbound".
+    pub fn label(&self, action: Action) -> String {
+        self.keys_for(action)
+            .next()
+            .map_or_else(|| "unbound".to_string(), |binding| binding.to_string())
+    }
+}
+
+fn to_event(binding: &KeyBinding) -> KeyEvent {
+    KeyEvent::new(binding.code, binding.modifiers)
+}
@@ -1,4 +1,19 @@
+pub mod addon;
 pub mod app;
+pub mod batch;
+pub mod battletag;
+pub mod cli;
+pub mod clipboard;
+pub mod error;
+pub mod history;
 pub mod input;
+pub mod keymap;
+pub mod output;
+pub mod profile;
+pub mod settings;
+pub mod status;
 pub mod terminal;
+pub mod theme;
 pub mod ui;
+pub mod undo;
+pub mod version;
@@ -1,18 +1,74 @@
+pub mod addon;
 pub mod app;
+pub mod batch;
+pub mod battletag;
+pub mod cli;
+pub mod clipboard;
+pub mod error;
+pub mod history;
 pub mod input;
+pub mod keymap;
+pub mod output;
+pub mod profile;
+pub mod settings;
+pub mod status;
 pub mod terminal;
+pub mod theme;
 pub mod ui;
+pub mod undo;
+pub mod version;
 
-use terminal::{restore_terminal, run_app, setup_terminal};
+use addon::AddonRegistry;
+use app::App;
+use clap::Parser;
+use cli::{Cli, EXIT_FAILURE};
+use history::History;
+use keymap::Keymap;
+use profile::ProfileStore;
+use settings::Settings;
+use std::{io, process::ExitCode};
+use terminal::{restore_terminal, run_app_with, setup_terminal};
+use theme::Theme;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let mut terminal = setup_terminal()?;
-    let res = run_app(&mut terminal);
-    restore_terminal(&mut terminal)?;
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    if !cli.is_interactive() {
+        return ExitCode::from(cli::run(&cli, &mut io::stdout(), &mut io::stderr()));
+    }
+
+    match run_tui(&cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err:?}");
+            ExitCode::from(EXIT_FAILURE)
+        }
+    }
+}
 
-    if let Err(err) = res {
-        println!("Error: {err:?}");
+fn run_tui(cli: &Cli) -> Result<(), Box<dyn std::error::Error>> {
+    let mut app = App::with_registry(AddonRegistry::load()?);
+    let settings = Settings::load()?;
+    app.apply_settings(&settings);
+    app.keymap = Keymap::with_overrides(&settings.keys)?;
+    app.clipboard = settings.clipboard.resolve();
+    app.theme = Theme::from_name(theme::select(
+        cli.theme.or(settings.theme),
+        theme::no_color(),
+    ));
+    app.settings_file = settings::default_path();
+    app.set_profiles(ProfileStore::load()?);
+    app.profiles_file = profile::default_path();
+    if settings.save_history {
+        app.history_file = history::default_path();
+        if let Some(path) = &app.history_file {
+            app.history = History::load_from(path)?;
+        }
     }
 
-    Ok(())
+    let mut terminal = setup_terminal()?;
+    let res = run_app_with(&mut terminal, app);
+    restore_terminal(&mut terminal)?;
+
+    Ok(res?)
 }
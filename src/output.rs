@@ -0,0 +1,196 @@
+use crate::{
+    app::{App, DecodedCode},
+    error::Error,
+};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::io::{self, Write};
+
+const CSV_HEADER: &str = "input,normalized_id,version,lowercase,code,errors";
+const DECODE_CSV_HEADER: &str = "code,battlenet_id,config,lowercase";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Bare unlock codes (tab-separated with the ID in batch mode)
+    #[default]
+    Plain,
+    /// A JSON object, or an array of objects in batch mode
+    Json,
+    /// One JSON object per line
+    Jsonl,
+    /// Comma-separated values with a header row
+    Csv,
+}
+
+/// Machine-readable result of generating an unlock code for one input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CodeRecord {
+    pub input: String,
+    pub normalized_id: String,
+    pub version: String,
+    pub lowercase: bool,
+    pub code: Option<String>,
+    pub errors: Vec<String>,
+}
+
+impl CodeRecord {
+    /// Generates a record for `input` with the version, case and addon config of `generator`.
+    pub fn generate(generator: &App, input: &str) -> Self {
+        let mut app = generator.clone();
+        app.battlenet_id = input.trim().to_string();
+
+        let (code, errors) = match app.generate_code() {
+            Ok(code) => (Some(code), Vec::new()),
+            Err(Error::InvalidBattleTag { .. }) => (None, describe_issues(&app)),
+            Err(error) => (None, vec![error.to_string()]),
+        };
+
+        Self {
+            input: input.to_string(),
+            normalized_id: app.normalized_id(),
+            version: app.version.to_string(),
+            lowercase: app.use_lowercase,
+            code,
+            errors,
+        }
+    }
+
+    /// A record for `input` that could not be read, with `error` as its only error.
+    pub fn unreadable(generator: &App, input: &str, error: &str) -> Self {
+        Self {
+            input: input.to_string(),
+            normalized_id: String::new(),
+            version: generator.version.to_string(),
+            lowercase: generator.use_lowercase,
+            code: None,
+            errors: vec![error.to_string()],
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.code.is_some()
+    }
+}
+
+fn describe_issues(app: &App) -> Vec<String> {
+    app.validation_issues()
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Machine-readable result of decoding an unlock code.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DecodeRecord {
+    pub code: String,
+    pub battlenet_id: String,
+    pub config: String,
+    pub lowercase: bool,
+}
+
+impl DecodeRecord {
+    pub fn new(code: &str, decoded: &DecodedCode) -> Self {
+        Self {
+            code: code.trim().to_string(),
+            battlenet_id: decoded.battlenet_id.clone(),
+            config: decoded.config_name.clone(),
+            lowercase: decoded.is_lowercase(),
+        }
+    }
+}
+
+/// Writes the result of the `decode` subcommand.
+pub fn write_decoded(
+    format: OutputFormat,
+    record: &DecodeRecord,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    match format {
+        OutputFormat::Plain => writeln!(out, "{}\t{}", record.battlenet_id, record.config),
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut *out, record)?;
+            writeln!(out)
+        }
+        OutputFormat::Jsonl => {
+            serde_json::to_writer(&mut *out, record)?;
+            writeln!(out)
+        }
+        OutputFormat::Csv => {
+            writeln!(out, "{DECODE_CSV_HEADER}")?;
+            writeln!(
+                out,
+                "{},{},{},{}",
+                escape_csv_field(&record.code),
+                escape_csv_field(&record.battlenet_id),
+                escape_csv_field(&record.config),
+                record.lowercase
+            )
+        }
+    }
+}
+
+/// Writes a single record, as produced by `--id`, in a structured format.
+pub fn write_record(
+    format: OutputFormat,
+    record: &CodeRecord,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    match format {
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut *out, record)?;
+            writeln!(out)
+        }
+        _ => write_records(format, std::slice::from_ref(record), out),
+    }
+}
+
+/// Writes a list of records, as produced by `--batch`, in a structured format.
+pub fn write_records(
+    format: OutputFormat,
+    records: &[CodeRecord],
+    out: &mut impl Write,
+) -> io::Result<()> {
+    match format {
+        OutputFormat::Plain => write_plain(records, out),
+        OutputFormat::Json => {
+            serde_json::to_writer_pretty(&mut *out, records)?;
+            writeln!(out)
+        }
+        OutputFormat::Jsonl => records.iter().try_for_each(|record| {
+            serde_json::to_writer(&mut *out, record)?;
+            writeln!(out)
+        }),
+        OutputFormat::Csv => write_csv(records, out),
+    }
+}
+
+fn write_plain(records: &[CodeRecord], out: &mut impl Write) -> io::Result<()> {
+    records
+        .iter()
+        .filter_map(|record| record.code.as_ref().map(|code| (record, code)))
+        .try_for_each(|(record, code)| writeln!(out, "{}\t{code}", record.input))
+}
+
+fn write_csv(records: &[CodeRecord], out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "{CSV_HEADER}")?;
+
+    records.iter().try_for_each(|record| {
+        let fields = [
+            escape_csv_field(&record.input),
+            escape_csv_field(&record.normalized_id),
+            escape_csv_field(&record.version),
+            record.lowercase.to_string(),
+            escape_csv_field(record.code.as_deref().unwrap_or_default()),
+            escape_csv_field(&record.errors.join("; ")),
+        ];
+        writeln!(out, "{}", fields.join(","))
+    })
+}
+
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
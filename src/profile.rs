@@ -0,0 +1,199 @@
+use crate::{
+    battletag::BattleTag,
+    error::{Error, Result},
+    settings,
+    version::GameVersion,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// File name of the profile store inside the config directory.
+pub const PROFILES_FILE_NAME: &str = "profiles.toml";
+
+/// A saved Battle.net account with its preferred generator settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile {
+    pub label: String,
+    pub battle_tag: String,
+    #[serde(default)]
+    pub version: GameVersion,
+    #[serde(default)]
+    pub lowercase: bool,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProfileFile {
+    #[serde(default, rename = "profile")]
+    profiles: Vec<Profile>,
+}
+
+/// A profile from the file that could not be added, and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedProfile {
+    pub profile: Profile,
+    pub error: Error,
+}
+
+/// Named profiles, kept in the order they were added.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProfileStore {
+    profiles: Vec<Profile>,
+    /// Invalid profiles from the file, written back unchanged when saving.
+    skipped: Vec<SkippedProfile>,
+}
+
+impl ProfileStore {
+    /// Loads the profile file from the config directory, starting empty when it does not exist.
+    pub fn load() -> Result<Self> {
+        match default_path() {
+            Some(path) if path.exists() => Self::load_from(&path),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path).map_err(|e| {
+            Error::InvalidProfiles(format!("could not read '{}': {e}", path.display()))
+        })?;
+
+        Self::from_toml(&text)
+    }
+
+    /// Parses `[[profile]]` tables. Profiles with an invalid BattleTag or a
+    /// duplicate label are skipped instead of failing the whole file.
+    pub fn from_toml(text: &str) -> Result<Self> {
+        let file: ProfileFile =
+            toml::from_str(text).map_err(|e| Error::InvalidProfiles(e.message().to_string()))?;
+
+        let mut store = Self::default();
+        for profile in file.profiles {
+            if let Err(error) = store.add(profile.clone()) {
+                store.skipped.push(SkippedProfile { profile, error });
+            }
+        }
+        Ok(store)
+    }
+
+    pub fn to_toml(&self) -> String {
+        let skipped = self.skipped.iter().map(|skipped| skipped.profile.clone());
+        let file = ProfileFile {
+            profiles: self.profiles.iter().cloned().chain(skipped).collect(),
+        };
+        toml::to_string(&file).expect("profiles serialize to TOML")
+    }
+
+    /// Writes the profiles to `path`, creating its directory if needed.
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        let write = || {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, self.to_toml())
+        };
+
+        write().map_err(|e| {
+            Error::InvalidProfiles(format!("could not write '{}': {e}", path.display()))
+        })
+    }
+
+    pub fn profiles(&self) -> &[Profile] {
+        &self.profiles
+    }
+
+    /// Profiles skipped when loading because they could not be added.
+    pub fn skipped(&self) -> &[SkippedProfile] {
+        &self.skipped
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Profile> {
+        self.profiles.get(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.profiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty()
+    }
+
+    /// Adds `profile` at the end. Labels must be unique, ignoring case, and the
+    /// BattleTag must be valid; it is stored normalized.
+    pub fn add(&mut self, mut profile: Profile) -> Result<()> {
+        profile.label = self.check_label(&profile.label, None)?;
+        profile.battle_tag = check_battle_tag(&profile)?;
+        self.profiles.push(profile);
+        Ok(())
+    }
+
+    pub fn rename(&mut self, index: usize, label: &str) -> Result<()> {
+        if index >= self.profiles.len() {
+            return Ok(());
+        }
+
+        self.profiles[index].label = self.check_label(label, Some(index))?;
+        Ok(())
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<Profile> {
+        (index < self.profiles.len()).then(|| self.profiles.remove(index))
+    }
+
+    fn check_label(&self, label: &str, renaming: Option<usize>) -> Result<String> {
+        let label = label.trim();
+        if label.is_empty() {
+            return Err(Error::InvalidProfiles("profile label is empty".to_string()));
+        }
+
+        let taken =
+            self.profiles.iter().enumerate().any(|(i, profile)| {
+                Some(i) != renaming && profile.label.eq_ignore_ascii_case(label)
+            });
+        match taken {
+            true => Err(Error::DuplicateProfile(label.to_string())),
+            false => Ok(label.to_string()),
+        }
+    }
+}
+
+fn check_battle_tag(profile: &Profile) -> Result<String> {
+    BattleTag::parse(&profile.battle_tag)
+        .map(|battle_tag| battle_tag.to_string())
+        .map_err(|issues| {
+            Error::InvalidProfiles(format!(
+                "profile '{}' has an invalid BattleTag '{}': {}",
+                profile.label, profile.battle_tag, issues[0]
+            ))
+        })
+}
+
+/// What the label being typed in the profile panel is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileEditKind {
+    Add,
+    Rename,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileEdit {
+    pub kind: ProfileEditKind,
+    pub label: String,
+}
+
+/// Selection and editing state of the profile list panel.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProfilePanel {
+    pub selected: usize,
+    pub edit: Option<ProfileEdit>,
+    /// Result of the last add, rename or delete, shown under the list.
+    pub message: Option<String>,
+}
+
+/// Location of the profile store, e.g. `~/.config/skillcapped-generator/profiles.toml`.
+pub fn default_path() -> Option<PathBuf> {
+    settings::config_dir().map(|dir| dir.join(PROFILES_FILE_NAME))
+}
@@ -0,0 +1,93 @@
+use crate::{
+    clipboard::ClipboardKind,
+    error::{Error, Result},
+    keymap::{Action, KeySpec},
+    theme::ThemeName,
+    version::GameVersion,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// File name of the settings file inside the config directory.
+pub const SETTINGS_FILE_NAME: &str = "config.toml";
+
+const CONFIG_DIR_NAME: &str = "skillcapped-generator";
+
+/// Defaults the terminal UI starts with, remembered between launches.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub version: GameVersion,
+    pub lowercase: bool,
+    /// Name of the addon config to generate for; unset uses the registry default.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub addon: Option<String>,
+    /// The Battle.net ID that was entered when the UI was last used.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub last_id: String,
+    /// Whether copied codes are saved to `history.toml` and restored at startup.
+    pub save_history: bool,
+    /// Clipboard backend for copying and pasting; `auto` picks one that works in the session.
+    pub clipboard: ClipboardKind,
+    /// Color theme of the terminal UI; unset follows `NO_COLOR`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme: Option<ThemeName>,
+    /// Key bindings that replace the defaults, e.g. `copy = "ctrl+y"` under `[keys]`.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub keys: BTreeMap<Action, KeySpec>,
+}
+
+impl Settings {
+    /// Loads the settings file from the config directory, falling back to the
+    /// defaults when it does not exist.
+    pub fn load() -> Result<Self> {
+        match default_path() {
+            Some(path) if path.exists() => Self::load_from(&path),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path).map_err(|e| {
+            Error::InvalidSettings(format!("could not read '{}': {e}", path.display()))
+        })?;
+
+        Self::from_toml(&text)
+    }
+
+    pub fn from_toml(text: &str) -> Result<Self> {
+        toml::from_str(text).map_err(|e| Error::InvalidSettings(e.message().to_string()))
+    }
+
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("settings serialize to TOML")
+    }
+
+    /// Writes the settings to `path`, creating its directory if needed.
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        let write = || {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, self.to_toml())
+        };
+
+        write().map_err(|e| {
+            Error::InvalidSettings(format!("could not write '{}': {e}", path.display()))
+        })
+    }
+}
+
+/// The generator's directory in the user's config directory, e.g. `~/.config/skillcapped-generator`.
+pub fn config_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(CONFIG_DIR_NAME))
+}
+
+/// Location of the settings file, e.g. `~/.config/skillcapped-generator/config.toml`.
+pub fn default_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(SETTINGS_FILE_NAME))
+}
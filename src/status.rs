@@ -0,0 +1,50 @@
+use std::time::{Duration, Instant};
+
+/// How long an info message stays in the status area.
+pub const INFO_DURATION: Duration = Duration::from_secs(2);
+
+/// How long an error message stays in the status area; long enough to read the reason.
+pub const ERROR_DURATION: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusLevel {
+    Info,
+    Error,
+}
+
+/// A timed message shown in the status area, e.g. after copying or when pasting failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusMessage {
+    pub level: StatusLevel,
+    pub text: String,
+    pub shown_at: Instant,
+}
+
+impl StatusMessage {
+    pub fn info(text: impl Into<String>) -> Self {
+        Self::new(StatusLevel::Info, text)
+    }
+
+    pub fn error(text: impl Into<String>) -> Self {
+        Self::new(StatusLevel::Error, text)
+    }
+
+    fn new(level: StatusLevel, text: impl Into<String>) -> Self {
+        Self {
+            level,
+            text: text.into(),
+            shown_at: Instant::now(),
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        match self.level {
+            StatusLevel::Info => INFO_DURATION,
+            StatusLevel::Error => ERROR_DURATION,
+        }
+    }
+
+    pub fn is_expired(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.shown_at) >= self.duration()
+    }
+}
@@ -1,10 +1,14 @@
 use crossterm::{
-    event::{self, Event, KeyEventKind},
+    event::{
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        Event, KeyEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::{Backend, CrosstermBackend},
+    layout::Rect,
     Terminal,
 };
 use std::{
@@ -12,18 +16,26 @@ use std::{
     time::{Duration, Instant},
 };
 
-use crate::{app::App, input::handle_key_event, ui::draw_ui};
+use crate::{
+    app::App,
+    input::{handle_key_event, handle_mouse_event, handle_paste_event},
+    ui::draw_ui,
+};
 
 pub fn run_app<B: Backend>(terminal: &mut Terminal<B>) -> io::Result<()> {
-    let mut app = App::new();
-    app.reset_input();
+    run_app_with(terminal, App::new())
+}
 
+/// Runs the event loop with an already configured `app`, saving its settings
+/// when they change and, with the last Battle.net ID, when quitting.
+pub fn run_app_with<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<()> {
     loop {
         if handle_app_iteration(terminal, &mut app)? {
-            return Ok(());
+            return app.save_settings().map_err(io::Error::other);
         }
 
-        update_copy_feedback(&mut app);
+        app.autosave_settings();
+        update_status(&mut app);
     }
 }
 
@@ -36,7 +48,9 @@ fn handle_app_iteration<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -
 
     // Handle events
     if event::poll(Duration::from_millis(200))? {
-        if let Some(should_quit) = handle_event(app)? {
+        let size = terminal.size()?;
+        let area = Rect::new(0, 0, size.width, size.height);
+        if let Some(should_quit) = handle_event(app, area)? {
             return Ok(should_quit);
         }
     }
@@ -44,12 +58,21 @@ fn handle_app_iteration<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -
     Ok(false)
 }
 
-fn handle_event(app: &mut App) -> io::Result<Option<bool>> {
+fn handle_event(app: &mut App, area: Rect) -> io::Result<Option<bool>> {
     let event = event::read()?;
 
     match event {
         Event::Key(key) if key.kind == KeyEventKind::Press => Ok(Some(handle_key_event(app, key))),
-        Event::Mouse(_) => Ok(None), // Ignore mouse events
+        // With bracketed paste, a terminal paste arrives as one event instead of
+        // a key press per character.
+        Event::Paste(text) => {
+            handle_paste_event(app, &text);
+            Ok(None)
+        }
+        Event::Mouse(mouse) => {
+            handle_mouse_event(app, mouse, area);
+            Ok(None)
+        }
         _ => Ok(None),
     }
 }
@@ -58,7 +81,12 @@ pub fn setup_terminal(
 ) -> Result<Terminal<CrosstermBackend<std::io::Stdout>>, Box<dyn std::error::Error>> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     Ok(Terminal::new(backend)?)
 }
@@ -67,13 +95,19 @@ pub fn restore_terminal(
     terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste
+    )?;
     terminal.show_cursor()?;
     Ok(())
 }
 
-pub fn update_copy_feedback(app: &mut App) {
-    app.copy_feedback = app
-        .copy_feedback
-        .filter(|&copy_instant| Instant::now().duration_since(copy_instant).as_secs() < 1);
+/// Clears the status message once it has expired.
+pub fn update_status(app: &mut App) {
+    if app.active_status(Instant::now()).is_none() {
+        app.status = None;
+    }
 }
@@ -0,0 +1,139 @@
+use clap::ValueEnum;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+use std::{env, fmt};
+
+/// A built-in color palette, chosen with `theme = "..."` in `config.toml` or `--theme`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    /// Bright colors for dark terminal backgrounds
+    #[default]
+    Dark,
+    /// Darker colors that stay readable on light backgrounds
+    Light,
+    /// Bright, strongly contrasting colors
+    HighContrast,
+    /// The terminal's own colors only
+    Monochrome,
+}
+
+impl ThemeName {
+    pub fn label(self) -> &'static str {
+        match self {
+            ThemeName::Dark => "dark",
+            ThemeName::Light => "light",
+            ThemeName::HighContrast => "high-contrast",
+            ThemeName::Monochrome => "monochrome",
+        }
+    }
+}
+
+impl fmt::Display for ThemeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Colors of the terminal UI, by what they are used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub name: ThemeName,
+    /// Section titles.
+    pub title: Color,
+    /// Labels, selector arrows and the borders of lists and inputs.
+    pub accent: Color,
+    /// Borders of the version and addon selectors.
+    pub frame: Color,
+    /// The selected option or list entry, hints and the help border.
+    pub highlight: Color,
+    pub success: Color,
+    pub error: Color,
+    pub text: Color,
+    /// Descriptions and unselected options.
+    pub muted: Color,
+    /// Separators, key hints and the About box.
+    pub subtle: Color,
+    pub link: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::from_name(ThemeName::default())
+    }
+}
+
+impl Theme {
+    pub fn from_name(name: ThemeName) -> Self {
+        match name {
+            ThemeName::Dark => Self {
+                name,
+                title: Color::Magenta,
+                accent: Color::Cyan,
+                frame: Color::Blue,
+                highlight: Color::Yellow,
+                success: Color::Green,
+                error: Color::Red,
+                text: Color::White,
+                muted: Color::Gray,
+                subtle: Color::DarkGray,
+                link: Color::Blue,
+            },
+            // Yellow, white and light gray disappear on white backgrounds.
+            ThemeName::Light => Self {
+                name,
+                title: Color::Magenta,
+                accent: Color::Blue,
+                frame: Color::Blue,
+                highlight: Color::Indexed(130),
+                success: Color::Indexed(28),
+                error: Color::Red,
+                text: Color::Black,
+                muted: Color::DarkGray,
+                subtle: Color::Indexed(244),
+                link: Color::Blue,
+            },
+            ThemeName::HighContrast => Self {
+                name,
+                title: Color::LightMagenta,
+                accent: Color::LightCyan,
+                frame: Color::White,
+                highlight: Color::LightYellow,
+                success: Color::LightGreen,
+                error: Color::LightRed,
+                text: Color::White,
+                muted: Color::White,
+                subtle: Color::Gray,
+                link: Color::LightCyan,
+            },
+            ThemeName::Monochrome => Self {
+                name,
+                title: Color::Reset,
+                accent: Color::Reset,
+                frame: Color::Reset,
+                highlight: Color::Reset,
+                success: Color::Reset,
+                error: Color::Reset,
+                text: Color::Reset,
+                muted: Color::Reset,
+                subtle: Color::Reset,
+                link: Color::Reset,
+            },
+        }
+    }
+}
+
+/// Picks the theme: one chosen on the command line or in the settings wins,
+/// otherwise `no_color` selects monochrome (see <https://no-color.org>).
+pub fn select(chosen: Option<ThemeName>, no_color: bool) -> ThemeName {
+    match chosen {
+        Some(name) => name,
+        None if no_color => ThemeName::Monochrome,
+        None => ThemeName::default(),
+    }
+}
+
+/// Whether the `NO_COLOR` environment variable is set to a non-empty value.
+pub fn no_color() -> bool {
+    env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
@@ -1,44 +1,414 @@
-use crate::app::App;
+use crate::{
+    app::{App, CodeInspection, Mode},
+    history::HistoryEntry,
+    keymap::Action,
+    profile::{Profile, ProfileEditKind},
+    status::{StatusLevel, StatusMessage},
+    theme::Theme,
+    version::GameVersion,
+};
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Position, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
 };
-use std::time::{Duration, Instant};
+use std::time::{Instant, SystemTime};
+
+/// Smallest terminal the UI is drawn in; below it a "terminal too small" message is shown.
+pub const MIN_WIDTH: u16 = 60;
+/// The collapsed layout: every section at its smallest, with one line of help.
+pub const MIN_HEIGHT: u16 = MAIN_SECTIONS_HEIGHT + 3 - COLLAPSED_SHARED_BORDERS;
+
+/// Terminals at least this wide show the help beside the main sections.
+pub const WIDE_MIN_WIDTH: u16 = 120;
+
+/// Width of the help column in the wide layout.
+const SIDEBAR_WIDTH: u16 = 40;
+
+const MARGIN: u16 = 2;
+
+/// Rows of the input, version, addon and code boxes at their smallest.
+const MAIN_SECTIONS_HEIGHT: u16 = 4 + 3 + 3 + 3;
+
+const FOOTER_HEIGHT: u16 = 3;
+
+/// Border rows saved when the five stacked sections share their borders.
+const COLLAPSED_SHARED_BORDERS: u16 = 4;
+
+/// How the screen is divided, depending on the terminal size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutKind {
+    /// Smaller than [`MIN_WIDTH`] x [`MIN_HEIGHT`]; only a message is drawn.
+    TooSmall,
+    /// The compact layout with neighbouring sections sharing a border row, for
+    /// terminals too short for it; the help shows only as many lines as fit.
+    Collapsed,
+    /// No margin and no About box, used when the regular layout does not fit;
+    /// status messages replace the help.
+    Compact,
+    Regular,
+    /// The help in a column to the right of the main sections.
+    Wide,
+}
+
+/// Screen areas of the main sections, shared by drawing and mouse handling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiLayout {
+    pub kind: LayoutKind,
+    pub input: Rect,
+    pub version: Rect,
+    pub addon: Rect,
+    pub code: Rect,
+    pub help: Rect,
+    /// The About box, which also shows status messages; `None` in the compact and collapsed layouts.
+    pub footer: Option<Rect>,
+}
+
+const SELECTOR_SEPARATOR: &str = " | ";
+
+/// Splits `area` into the UI sections for `app`; the help box grows to fit its items.
+pub fn ui_layout(app: &App, area: Rect) -> UiLayout {
+    let help_height = |width: u16| {
+        let lines = create_help_lines(app, usize::from(width.saturating_sub(2)), usize::MAX);
+        u16::try_from(lines.len())
+            .unwrap_or(u16::MAX)
+            .saturating_add(2)
+    };
+
+    let regular_help_height = help_height(area.width.saturating_sub(2 * MARGIN));
+    let regular_height = |vertical_margin: u16| {
+        2 * vertical_margin + MAIN_SECTIONS_HEIGHT + regular_help_height + FOOTER_HEIGHT
+    };
+    // Give up the top and bottom margin rows before dropping the About box.
+    let vertical_margin = match area.height >= regular_height(MARGIN) {
+        true => MARGIN,
+        false => 1,
+    };
+    let compact_help_height = help_height(area.width);
+    let kind = match (area.width, area.height) {
+        (width, height) if width < MIN_WIDTH || height < MIN_HEIGHT => LayoutKind::TooSmall,
+        (_, height) if height < MAIN_SECTIONS_HEIGHT + compact_help_height => LayoutKind::Collapsed,
+        (_, height) if height < regular_height(vertical_margin) => LayoutKind::Compact,
+        (width, _) if width >= WIDE_MIN_WIDTH => LayoutKind::Wide,
+        _ => LayoutKind::Regular,
+    };
+
+    let main_rows = |area: Rect, help: u16| {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(4), // Input
+                Constraint::Length(3), // Version
+                Constraint::Length(3), // Addon config
+                Constraint::Min(3),    // Code output
+                Constraint::Length(help),
+            ])
+            .split(area)
+    };
+    let sections = |rows: &[Rect], help: Rect, footer: Option<Rect>| UiLayout {
+        kind,
+        input: rows[0],
+        version: rows[1],
+        addon: rows[2],
+        code: rows[3],
+        help,
+        footer,
+    };
+
+    match kind {
+        LayoutKind::TooSmall => UiLayout {
+            kind,
+            input: Rect::default(),
+            version: Rect::default(),
+            addon: Rect::default(),
+            code: Rect::default(),
+            help: Rect::default(),
+            footer: None,
+        },
+        LayoutKind::Collapsed => {
+            let rows = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(4),
+                    Constraint::Length(3),
+                    Constraint::Length(3),
+                    Constraint::Min(3),
+                    Constraint::Max(compact_help_height),
+                ])
+                .spacing(-1)
+                .split(area);
+            sections(&rows, rows[4], None)
+        }
+        LayoutKind::Compact => {
+            let rows = main_rows(area, compact_help_height);
+            sections(&rows, rows[4], None)
+        }
+        LayoutKind::Regular | LayoutKind::Wide => {
+            let inner = area.inner(Margin::new(MARGIN, vertical_margin));
+            let outer = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(FOOTER_HEIGHT)])
+                .split(inner);
+
+            if kind == LayoutKind::Regular {
+                let rows = main_rows(outer[0], help_height(outer[0].width));
+                return sections(&rows, rows[4], Some(outer[1]));
+            }
+
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(1), Constraint::Length(SIDEBAR_WIDTH)])
+                .split(outer[0]);
+            let rows = main_rows(columns[0], 0);
+            sections(&rows, columns[1], Some(outer[1]))
+        }
+    }
+}
 
 pub fn draw_ui(f: &mut Frame, app: &App) {
-    let size = f.area();
-
-    let layout = Layout::default()
-        .direction(Direction::Vertical)
-        .margin(2)
-        .constraints([
-            Constraint::Length(3), // Input
-            Constraint::Length(3), // Version
-            Constraint::Min(1),    // Code output
-            Constraint::Length(4), // Help
-            Constraint::Length(3), // Footer
-        ])
-        .split(size);
-
-    draw_input_section(f, app, layout[0]);
-    draw_version_section(f, app, layout[1]);
-    draw_code_section(f, app, layout[2]);
-    draw_help_section(f, layout[3]);
-    draw_footer_section(f, layout[4]);
+    let layout = ui_layout(app, f.area());
+    if layout.kind == LayoutKind::TooSmall {
+        draw_too_small_screen(f, app, f.area());
+        return;
+    }
+
+    match app.mode {
+        Mode::Generate => {
+            draw_input_section(f, app, layout.input);
+            draw_version_section(f, app, layout.version);
+            draw_addon_section(f, app, layout.addon);
+            draw_code_section(f, app, layout.code);
+        }
+        Mode::Verify => {
+            draw_verify_input_section(f, app, layout.input);
+            draw_version_section(f, app, layout.version);
+            // Decoding tries every addon config, so the result takes the selector's place.
+            draw_verify_result_section(f, app, layout.addon.union(layout.code));
+        }
+        Mode::Profiles => draw_profiles_section(f, app, layout.input.union(layout.code)),
+        Mode::History => draw_history_section(f, app, layout.input.union(layout.code)),
+        Mode::PasteSelection => {
+            draw_input_section(f, app, layout.input);
+            draw_paste_selection_section(f, app, layout.version.union(layout.code));
+        }
+    }
+
+    match (layout.footer, app.active_status(Instant::now())) {
+        (Some(footer), _) => {
+            draw_help_section(f, app, layout.help);
+            draw_footer_section(f, app, footer);
+        }
+        // Without the About box, messages take the help's place until they expire.
+        (None, Some(status)) => draw_status_section(f, status, &app.theme, layout.help),
+        (None, None) => draw_help_section(f, app, layout.help),
+    }
+}
+
+fn draw_too_small_screen(f: &mut Frame, app: &App, area: Rect) {
+    let lines = vec![
+        Line::from(Span::styled(
+            "📏 Terminal too small",
+            Style::default()
+                .fg(app.theme.highlight)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!(
+                "{}x{}, needs at least {MIN_WIDTH}x{MIN_HEIGHT}",
+                area.width, area.height
+            ),
+            Style::default().fg(app.theme.muted),
+        )),
+        Line::from(Span::styled(
+            format!(
+                "Resize the window or press {} to quit",
+                app.keymap.label(Action::Quit)
+            ),
+            Style::default().fg(app.theme.muted),
+        )),
+    ];
+
+    let height = u16::try_from(lines.len()).unwrap_or(u16::MAX);
+    let top = area.y + area.height.saturating_sub(height) / 2;
+    let message_area = Rect::new(area.x, top, area.width, height.min(area.height));
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        message_area,
+    );
+}
+
+/// What a mouse click at a screen position points at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClickTarget {
+    /// A character position in the Battle.net ID input.
+    Input(usize),
+    Version(GameVersion),
+    /// The name of an addon config in the selector.
+    Addon(String),
+    Code,
+    Link,
+}
+
+/// Finds the clickable element drawn at (`column`, `row`) for a screen of size `area`.
+pub fn click_target(app: &App, area: Rect, column: u16, row: u16) -> Option<ClickTarget> {
+    let layout = ui_layout(app, area);
+    let position = Position::new(column, row);
+    let generating = app.mode == Mode::Generate;
+
+    if generating && layout.input.contains(position) {
+        let (_, _, status_emoji) = get_input_styling(app);
+        let prefix = Line::from(create_input_spans(
+            status_emoji,
+            "",
+            Style::default(),
+            &app.theme,
+        ))
+        .width();
+        let offset = usize::from(column.saturating_sub(layout.input.x + 1)).saturating_sub(prefix);
+        return Some(ClickTarget::Input(input_position_at(
+            &app.battlenet_id,
+            offset,
+        )));
+    }
+
+    if matches!(app.mode, Mode::Generate | Mode::Verify) && layout.version.contains(position) {
+        let current = GameVersion::ALL.iter().position(|&v| v == app.version);
+        return selector_click(&version_options(app), current, layout.version, column)
+            .map(|i| ClickTarget::Version(GameVersion::ALL[i]));
+    }
+
+    if generating && layout.addon.contains(position) {
+        let configs = app.registry.configs();
+        return selector_click(
+            &addon_options(app),
+            current_addon_index(app),
+            layout.addon,
+            column,
+        )
+        .map(|i| ClickTarget::Addon(configs[i].name.clone()));
+    }
+
+    if generating && layout.code.contains(position) {
+        return Some(ClickTarget::Code);
+    }
+
+    let footer = layout
+        .footer
+        .filter(|_| app.active_status(Instant::now()).is_none());
+    if let Some(footer) = footer.filter(|footer| footer.contains(position)) {
+        let spans = create_about_spans(app);
+        let start = footer.x + 1;
+        let before: usize = spans[..ABOUT_LINK_SPAN].iter().map(Span::width).sum();
+        let link_start = usize::from(start) + before;
+        let link_end = link_start + spans[ABOUT_LINK_SPAN].width();
+        return (link_start..link_end)
+            .contains(&usize::from(column))
+            .then_some(ClickTarget::Link);
+    }
+
+    None
+}
+
+/// The character position for a click `offset` cells into `text`; clicking the
+/// right half of a character places the cursor after it.
+fn input_position_at(text: &str, offset: usize) -> usize {
+    let mut start = 0;
+    for (i, c) in text.chars().enumerate() {
+        let width = Span::raw(c.to_string()).width();
+        if offset < start + width.div_ceil(2) {
+            return i;
+        }
+        start += width;
+    }
+    text.chars().count()
+}
+
+/// Index of the option a click on a selector picks: the one under `column`, or
+/// the next one when the selector only shows the current option.
+fn selector_click(
+    options: &[Span],
+    current: Option<usize>,
+    area: Rect,
+    column: u16,
+) -> Option<usize> {
+    match selector_fits(options, area) {
+        true => selector_index_at(options, area, column),
+        false if options.is_empty() => None,
+        false => Some(current.map_or(0, |i| (i + 1) % options.len())),
+    }
+}
+
+/// Index of the selector option under `column`, with options separated by " | ".
+fn selector_index_at(spans: &[Span], area: Rect, column: u16) -> Option<usize> {
+    let column = usize::from(column);
+    let mut start = usize::from(area.x) + 1;
+    for (i, span) in spans.iter().enumerate() {
+        if i > 0 {
+            start += SELECTOR_SEPARATOR.len();
+        }
+        let end = start + span.width();
+        if (start..end).contains(&column) {
+            return Some(i);
+        }
+        start = end;
+    }
+    None
 }
 
 fn draw_input_section(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     let (input_style, input_border_color, status_emoji) = get_input_styling(app);
     let input_text = get_input_display_text(app);
-    let input_spans = create_input_spans(status_emoji, input_text, input_style);
+    let input_spans = create_input_spans(status_emoji, input_text, input_style, &app.theme);
+    let lines = vec![Line::from(input_spans), create_validation_line(app)];
 
     f.render_widget(
-        Paragraph::new(Line::from(input_spans)).block(create_input_block(input_border_color)),
+        Paragraph::new(lines).block(create_input_block(input_border_color, &app.theme)),
         area,
     );
+    f.set_cursor_position(get_cursor_position(app, status_emoji, area));
+}
+
+/// Screen position of the input cursor, just inside the input block's border.
+fn get_cursor_position(
+    app: &App,
+    status_emoji: &'static str,
+    area: ratatui::layout::Rect,
+) -> (u16, u16) {
+    let before_cursor: String = app
+        .battlenet_id
+        .chars()
+        .take(app.cursor_position())
+        .collect();
+    let width = Line::from(create_input_spans(
+        status_emoji,
+        &before_cursor,
+        Style::default(),
+        &app.theme,
+    ))
+    .width();
+
+    let x = area.x + 1 + u16::try_from(width).unwrap_or(u16::MAX);
+    let max_x = area.right().saturating_sub(2);
+    (x.min(max_x), area.y + 1)
+}
+
+fn create_validation_line(app: &App) -> Line<'static> {
+    if app.battlenet_id.is_empty() {
+        return Line::default();
+    }
+
+    match app.validation_issues().first() {
+        Some(issue) => Line::from(Span::styled(
+            format!("⚠️  {issue}"),
+            Style::default().fg(app.theme.error),
+        )),
+        None => Line::default(),
+    }
 }
 
 fn get_input_display_text(app: &App) -> &str {
@@ -53,50 +423,439 @@ fn create_input_spans<'a>(
     status_emoji: &'static str,
     input_text: &'a str,
     input_style: Style,
+    theme: &Theme,
 ) -> Vec<Span<'a>> {
     vec![
         Span::styled(status_emoji, Style::default()),
         Span::styled(
             " Battle.net ID: ",
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme.accent)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::styled(input_text, input_style.add_modifier(Modifier::BOLD)),
     ]
 }
 
-fn create_input_block(border_color: Color) -> Block<'static> {
+fn create_input_block(border_color: Color, theme: &Theme) -> Block<'static> {
     Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(border_color))
         .title_style(
             Style::default()
-                .fg(Color::Magenta)
+                .fg(theme.title)
                 .add_modifier(Modifier::BOLD),
         )
         .title("💻 Input")
 }
 
-fn draw_version_section(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
-    let version_line = Line::from(vec![
-        create_version_span("Classic", &app.version, "classic"),
-        Span::styled(" | ", Style::default().fg(Color::Cyan)),
-        create_version_span("Retail", &app.version, "retail"),
-        Span::styled("    ⭳ Tab to switch", Style::default().fg(Color::Cyan)),
+fn draw_verify_input_section(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let (input_text, input_color) = match app.verify_input.is_empty() {
+        true => (
+            format!(
+                "Paste an unlock code... ({})",
+                app.keymap.label(Action::Paste)
+            ),
+            app.theme.accent,
+        ),
+        false => (app.verify_input.clone(), app.theme.text),
+    };
+
+    let input_line = Line::from(vec![
+        Span::styled("🔍", Style::default()),
+        Span::styled(
+            " Unlock code: ",
+            Style::default()
+                .fg(app.theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            input_text,
+            Style::default()
+                .fg(input_color)
+                .add_modifier(Modifier::BOLD),
+        ),
     ]);
 
+    f.render_widget(
+        Paragraph::new(input_line).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(app.theme.accent))
+                .title_style(
+                    Style::default()
+                        .fg(app.theme.title)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .title("🔍 Verify Unlock Code"),
+        ),
+        area,
+    );
+}
+
+fn draw_verify_result_section(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let (lines, color) = get_verify_result_info(app);
+
+    f.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(color))
+                .title_style(
+                    Style::default()
+                        .fg(app.theme.title)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .title("🔎 Decoded"),
+        ),
+        area,
+    );
+}
+
+fn get_verify_result_info(app: &App) -> (Vec<Line<'static>>, Color) {
+    if app.verify_input.trim().is_empty() {
+        let hint = Line::from(Span::styled(
+            "⚠️  Paste an unlock code to see what it was generated for",
+            Style::default().fg(app.theme.highlight),
+        ));
+        return (vec![hint], app.theme.highlight);
+    }
+
+    match app.inspect_code(&app.verify_input) {
+        Ok(inspection) => (create_inspection_lines(app, &inspection), app.theme.success),
+        Err(e) => (
+            vec![Line::from(Span::styled(
+                format!("❌ {e}"),
+                Style::default().fg(app.theme.error),
+            ))],
+            app.theme.error,
+        ),
+    }
+}
+
+fn create_inspection_lines(app: &App, inspection: &CodeInspection) -> Vec<Line<'static>> {
+    let (current_id, current_color) =
+        match (app.battlenet_id.is_empty(), inspection.matches_current_id) {
+            (true, _) => ("No Battle.net ID entered".to_string(), app.theme.muted),
+            (false, true) => (
+                format!("✅ Matches {}", app.battlenet_id),
+                app.theme.success,
+            ),
+            (false, false) => (
+                format!("❌ Does not match {}", app.battlenet_id),
+                app.theme.error,
+            ),
+        };
+
+    vec![
+        create_inspection_line(
+            "Battle.net ID: ",
+            inspection.battlenet_id.clone(),
+            app.theme.text,
+            &app.theme,
+        ),
+        create_inspection_line(
+            "Addon config:  ",
+            inspection.config_name.clone(),
+            app.theme.text,
+            &app.theme,
+        ),
+        create_inspection_line(
+            "Lowercased:    ",
+            if inspection.lowercased { "yes" } else { "no" }.to_string(),
+            app.theme.text,
+            &app.theme,
+        ),
+        create_inspection_line("Current ID:    ", current_id, current_color, &app.theme),
+    ]
+}
+
+fn create_inspection_line(
+    label: &'static str,
+    value: String,
+    color: Color,
+    theme: &Theme,
+) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(
+            label,
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            value,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ),
+    ])
+}
+
+fn draw_version_section(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let current = GameVersion::ALL.iter().position(|&v| v == app.version);
+    let version_line = create_selector_line(version_options(app), current, area, &app.theme);
+
     f.render_widget(
         Paragraph::new(version_line).block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Blue))
+                .border_style(Style::default().fg(app.theme.frame))
+                .title_style(
+                    Style::default()
+                        .fg(app.theme.title)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .title(format!(
+                    "🎮 Version ({} to switch)",
+                    app.keymap.label(Action::ToggleVersion)
+                )),
+        ),
+        area,
+    );
+}
+
+fn draw_profiles_section(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    // Two border rows and one row for the prompt under the list.
+    let visible = usize::from(area.height.saturating_sub(3)).max(1);
+    let selected = app.profile_panel.selected;
+    let offset = scroll_offset(selected, visible);
+
+    let mut lines: Vec<Line> = app
+        .profiles
+        .profiles()
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .map(|(i, profile)| create_profile_line(profile, i == selected, &app.theme))
+        .collect();
+
+    if app.profiles.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No profiles yet. Enter a Battle.net ID, then press 'a' here to save it.",
+            Style::default().fg(app.theme.muted),
+        )));
+    }
+    lines.resize(visible, Line::default());
+    lines.push(create_profile_prompt_line(app));
+
+    f.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(app.theme.accent))
                 .title_style(
                     Style::default()
-                        .fg(Color::Magenta)
+                        .fg(app.theme.title)
                         .add_modifier(Modifier::BOLD),
                 )
-                .title("🎮 Version"),
+                .title(format!(
+                    "👤 Profiles ({} to close)",
+                    app.keymap.label(Action::Profiles)
+                )),
+        ),
+        area,
+    );
+}
+
+/// First row to show so that `selected` stays inside a list of `visible` rows.
+fn scroll_offset(selected: usize, visible: usize) -> usize {
+    selected.saturating_sub(visible.saturating_sub(1))
+}
+
+fn draw_history_section(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let visible = usize::from(area.height.saturating_sub(2)).max(1);
+    let selected = app.history_selected;
+    let now = SystemTime::now();
+
+    let mut lines: Vec<Line> = app
+        .history
+        .entries()
+        .enumerate()
+        .skip(scroll_offset(selected, visible))
+        .take(visible)
+        .map(|(i, entry)| create_history_line(entry, i == selected, now, &app.theme))
+        .collect();
+
+    if app.history.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "No codes copied yet. Codes copied with {} show up here.",
+                app.keymap.label(Action::Copy)
+            ),
+            Style::default().fg(app.theme.muted),
+        )));
+    }
+
+    f.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(app.theme.accent))
+                .title_style(
+                    Style::default()
+                        .fg(app.theme.title)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .title("🕘 History (Enter to copy again)"),
+        ),
+        area,
+    );
+}
+
+fn draw_paste_selection_section(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let visible = usize::from(area.height.saturating_sub(2)).max(1);
+    let selected = app.paste_selected;
+
+    let lines: Vec<Line> = app
+        .paste_candidates
+        .iter()
+        .enumerate()
+        .skip(scroll_offset(selected, visible))
+        .take(visible)
+        .map(|(i, tag)| {
+            let (marker, color) = match i == selected {
+                true => ("▶ ", app.theme.highlight),
+                false => ("  ", app.theme.text),
+            };
+            Line::from(vec![
+                Span::styled(marker, Style::default().fg(color)),
+                Span::styled(
+                    tag.to_string(),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+            ])
+        })
+        .collect();
+
+    f.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(app.theme.accent))
+                .title_style(
+                    Style::default()
+                        .fg(app.theme.title)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .title(format!(
+                    "📋 {} BattleTags found in the paste (Enter to use)",
+                    app.paste_candidates.len()
+                )),
+        ),
+        area,
+    );
+}
+
+fn create_history_line(
+    entry: &HistoryEntry,
+    is_selected: bool,
+    now: SystemTime,
+    theme: &Theme,
+) -> Line<'static> {
+    let (marker, color) = match is_selected {
+        true => ("▶ ", theme.highlight),
+        false => ("  ", theme.text),
+    };
+
+    Line::from(vec![
+        Span::styled(marker, Style::default().fg(color)),
+        Span::styled(
+            format!("{:<10}", entry.age_label(now)),
+            Style::default().fg(theme.muted),
+        ),
+        Span::styled(
+            format!("{:<20}", entry.battlenet_id),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("{:<13}{:<5}", entry.version.label(), entry.config),
+            Style::default().fg(theme.muted),
+        ),
+        Span::styled(entry.code.clone(), Style::default().fg(color)),
+    ])
+}
+
+fn create_profile_line(profile: &Profile, is_selected: bool, theme: &Theme) -> Line<'static> {
+    let (marker, color) = match is_selected {
+        true => ("▶ ", theme.highlight),
+        false => ("  ", theme.text),
+    };
+    let case = if profile.lowercase { ", lowercase" } else { "" };
+
+    Line::from(vec![
+        Span::styled(marker, Style::default().fg(color)),
+        Span::styled(
+            format!("{:<16}", profile.label),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("{:<20}", profile.battle_tag),
+            Style::default().fg(color),
+        ),
+        Span::styled(
+            format!("{}{case}", profile.version.label()),
+            Style::default().fg(theme.muted),
+        ),
+    ])
+}
+
+fn create_profile_prompt_line(app: &App) -> Line<'static> {
+    if let Some(edit) = &app.profile_panel.edit {
+        let prompt = match edit.kind {
+            ProfileEditKind::Add => "New profile label: ",
+            ProfileEditKind::Rename => "Rename to: ",
+        };
+        return Line::from(vec![
+            Span::styled(
+                prompt,
+                Style::default()
+                    .fg(app.theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("{}▏", edit.label),
+                Style::default()
+                    .fg(app.theme.text)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "  (Enter to save, Esc to cancel)",
+                Style::default().fg(app.theme.subtle),
+            ),
+        ]);
+    }
+
+    match &app.profile_panel.message {
+        Some(message) => Line::from(Span::styled(
+            message.clone(),
+            Style::default().fg(app.theme.highlight),
+        )),
+        None => Line::default(),
+    }
+}
+
+fn draw_addon_section(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let addon_line = create_selector_line(
+        addon_options(app),
+        current_addon_index(app),
+        area,
+        &app.theme,
+    );
+
+    f.render_widget(
+        Paragraph::new(addon_line).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(app.theme.frame))
+                .title_style(
+                    Style::default()
+                        .fg(app.theme.title)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .title(format!(
+                    "🧩 Addon ({} to switch)",
+                    app.keymap.label(Action::CycleAddon)
+                )),
         ),
         area,
     );
@@ -116,26 +875,46 @@ fn draw_code_section(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
                 .border_style(Style::default().fg(code_color))
                 .title_style(
                     Style::default()
-                        .fg(Color::Magenta)
+                        .fg(app.theme.title)
                         .add_modifier(Modifier::BOLD),
                 )
-                .title(copy_title),
+                .title(copy_title)
+                .title(create_lowercase_title(app).right_aligned()),
         ),
         area,
     );
 }
 
-fn draw_help_section(f: &mut Frame, area: ratatui::layout::Rect) {
-    let help_spans = create_help_spans();
+/// Whether the Battle.net ID is lowercased before encoding, shown on the code box.
+fn create_lowercase_title(app: &App) -> Line<'static> {
+    let (state, color) = match app.use_lowercase {
+        true => ("on", app.theme.highlight),
+        false => ("off", app.theme.muted),
+    };
+
+    Line::from(vec![
+        Span::styled(" 🔡 Lowercase: ", Style::default().fg(app.theme.muted)),
+        Span::styled(
+            state,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" "),
+    ])
+}
+
+fn draw_help_section(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let width = usize::from(area.width.saturating_sub(2));
+    let max_lines = usize::from(area.height.saturating_sub(2));
+    let help_lines = create_help_lines(app, width, max_lines);
 
     f.render_widget(
-        Paragraph::new(Line::from(help_spans)).block(
+        Paragraph::new(help_lines).wrap(Wrap { trim: true }).block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Yellow))
+                .border_style(Style::default().fg(app.theme.highlight))
                 .title_style(
                     Style::default()
-                        .fg(Color::Magenta)
+                        .fg(app.theme.title)
                         .add_modifier(Modifier::BOLD),
                 )
                 .title("❓ Help"),
@@ -144,60 +923,196 @@ fn draw_help_section(f: &mut Frame, area: ratatui::layout::Rect) {
     );
 }
 
-fn create_help_spans() -> Vec<Span<'static>> {
-    let help_items = [
-        ("Type/Paste: ", "Enter Battle.net ID"),
-        ("Esc: ", "Clear"),
-        ("Ctrl+C: ", "Copy"),
-        ("Ctrl+V: ", "Paste"),
-        ("Ctrl+Q: ", "Quit"),
-    ];
+/// The help items for `mode`, packed into at most `max_lines` lines of at most
+/// `width` cells without splitting an item across lines.
+///
+/// When they do not fit, items are dropped from the end, keeping the ones that
+/// switch panels or leave until nothing else is left to drop.
+fn create_help_lines(app: &App, width: usize, max_lines: usize) -> Vec<Line<'static>> {
+    let mut help_items = help_items(app);
+    let mut lines = pack_help_lines(app, &help_items, width);
 
-    let mut spans = vec![Span::styled("⌨️  ", Style::default().fg(Color::Yellow))];
+    while lines.len() > max_lines && help_items.len() > 1 {
+        let dropped = help_items
+            .iter()
+            .rposition(|(_, _, keep)| !keep)
+            .unwrap_or(0);
+        help_items.remove(dropped);
+        lines = pack_help_lines(app, &help_items, width);
+    }
 
-    for (i, (command, description)) in help_items.iter().enumerate() {
-        if i > 0 {
-            spans.push(Span::styled(" | ", Style::default().fg(Color::DarkGray)));
-        }
+    lines
+}
 
-        spans.push(Span::styled(
-            *command,
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        ));
-        spans.push(Span::styled(*description, Style::default().fg(Color::Gray)));
-    }
+/// Key, description and whether the item is kept when the help is truncated.
+type HelpItem = (String, &'static str, bool);
 
-    spans
+fn help_items(app: &App) -> Vec<HelpItem> {
+    let key = |action| app.keymap.label(action);
+    match app.mode {
+        Mode::Generate => vec![
+            (key(Action::Clear), "Clear", false),
+            (key(Action::Copy), "Copy", false),
+            (key(Action::Paste), "Paste", false),
+            (key(Action::ToggleCase), "Lowercase on/off", false),
+            (key(Action::Undo), "Undo", false),
+            (key(Action::Redo), "Redo", false),
+            (key(Action::Profiles), "Profiles", true),
+            (key(Action::History), "History", false),
+            (key(Action::ToggleDecode), "Decode", true),
+            (key(Action::Quit), "Quit", true),
+        ],
+        Mode::Verify => vec![
+            ("Esc".to_string(), "Clear", false),
+            (key(Action::Copy), "Copy", false),
+            (key(Action::Paste), "Paste", false),
+            (key(Action::ToggleDecode), "Generate", true),
+            (key(Action::Quit), "Quit", true),
+        ],
+        Mode::Profiles => vec![
+            ("↑/↓".to_string(), "Select", false),
+            ("Enter".to_string(), "Use", true),
+            (key(Action::Copy), "Copy code", false),
+            ("a".to_string(), "Add current", false),
+            ("r".to_string(), "Rename", false),
+            ("d".to_string(), "Delete", false),
+            ("Esc".to_string(), "Back", true),
+        ],
+        Mode::History => vec![
+            ("↑/↓".to_string(), "Select", false),
+            (format!("Enter/{}", key(Action::Copy)), "Copy again", false),
+            ("Esc".to_string(), "Back", true),
+            (key(Action::Quit), "Quit", true),
+        ],
+        Mode::PasteSelection => vec![
+            ("↑/↓".to_string(), "Select", false),
+            ("Enter".to_string(), "Use", true),
+            ("Esc".to_string(), "Cancel", true),
+            (key(Action::Quit), "Quit", true),
+        ],
+    }
 }
 
-fn draw_footer_section(f: &mut Frame, area: ratatui::layout::Rect) {
-    f.render_widget(
-        Paragraph::new(Line::from(vec![
-            Span::styled("Made by ", Style::default().fg(Color::Gray)),
+fn pack_help_lines(app: &App, help_items: &[HelpItem], width: usize) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut spans = vec![Span::styled(
+        "⌨️  ",
+        Style::default().fg(app.theme.highlight),
+    )];
+    let mut line_width = Line::from(spans.clone()).width();
+    let mut line_has_items = false;
+
+    for (command, description, _) in help_items {
+        let item = [
             Span::styled(
-                "Xerrion",
+                format!("{command}: "),
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(app.theme.text)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" | 🔗 ", Style::default().fg(Color::Gray)),
+            Span::styled(*description, Style::default().fg(app.theme.muted)),
+        ];
+        let item_width: usize = item.iter().map(Span::width).sum();
+
+        if line_has_items && line_width + SELECTOR_SEPARATOR.len() + item_width > width {
+            lines.push(Line::from(std::mem::take(&mut spans)));
+            line_width = 0;
+            line_has_items = false;
+        }
+        if line_has_items {
+            spans.push(Span::styled(
+                SELECTOR_SEPARATOR,
+                Style::default().fg(app.theme.subtle),
+            ));
+            line_width += SELECTOR_SEPARATOR.len();
+        }
+
+        spans.extend(item);
+        line_width += item_width;
+        line_has_items = true;
+    }
+    lines.push(Line::from(spans));
+
+    lines
+}
+
+fn draw_footer_section(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    match app.active_status(Instant::now()) {
+        Some(status) => draw_status_section(f, status, &app.theme, area),
+        None => draw_about_section(f, app, area),
+    }
+}
+
+/// Shows the current info or error message in place of the About box until it expires.
+fn draw_status_section(
+    f: &mut Frame,
+    status: &StatusMessage,
+    theme: &Theme,
+    area: ratatui::layout::Rect,
+) {
+    let (icon, color) = match status.level {
+        StatusLevel::Info => ("✅ ", theme.success),
+        StatusLevel::Error => ("⚠️  ", theme.error),
+    };
+
+    f.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled(icon, Style::default().fg(color)),
             Span::styled(
-                "https://github.com/Xerrion",
-                Style::default()
-                    .fg(Color::Blue)
-                    .add_modifier(Modifier::UNDERLINED)
-                    .add_modifier(Modifier::BOLD),
+                status.text.clone(),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" (Ctrl+G to open)", Style::default().fg(Color::DarkGray)),
         ]))
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::DarkGray))
-                .title_style(Style::default().fg(Color::DarkGray))
-                .title("ℹ️  About"),
+                .border_style(Style::default().fg(color))
+                .title_style(
+                    Style::default()
+                        .fg(theme.title)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .title("📣 Status"),
+        ),
+        area,
+    );
+}
+
+/// Position of the GitHub link in [`create_about_spans`].
+const ABOUT_LINK_SPAN: usize = 3;
+
+fn create_about_spans(app: &App) -> Vec<Span<'static>> {
+    vec![
+        Span::styled("Made by ", Style::default().fg(app.theme.muted)),
+        Span::styled(
+            "Xerrion",
+            Style::default()
+                .fg(app.theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(" | 🔗 ", Style::default().fg(app.theme.muted)),
+        Span::styled(
+            "https://github.com/Xerrion",
+            Style::default()
+                .fg(app.theme.link)
+                .add_modifier(Modifier::UNDERLINED)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(" ({} to open)", app.keymap.label(Action::OpenLink)),
+            Style::default().fg(app.theme.subtle),
+        ),
+    ]
+}
+
+fn draw_about_section(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    f.render_widget(
+        Paragraph::new(Line::from(create_about_spans(app))).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(app.theme.subtle))
+                .title_style(Style::default().fg(app.theme.subtle))
+                .title(format!("ℹ️  About | 📋 Clipboard: {}", app.clipboard)),
         ),
         area,
     );
@@ -205,27 +1120,110 @@ fn draw_footer_section(f: &mut Frame, area: ratatui::layout::Rect) {
 
 fn get_input_styling(app: &App) -> (Style, Color, &'static str) {
     match (app.battlenet_id.is_empty(), app.is_valid_battlenet_id()) {
-        (true, _) => (Style::default().fg(Color::Cyan), Color::Cyan, "💭"),
-        (false, true) => (Style::default().fg(Color::Green), Color::Green, "✅"),
-        (false, false) => (Style::default().fg(Color::Red), Color::Red, "❌"),
+        (true, _) => (
+            Style::default().fg(app.theme.accent),
+            app.theme.accent,
+            "💭",
+        ),
+        (false, true) => (
+            Style::default().fg(app.theme.success),
+            app.theme.success,
+            "✅",
+        ),
+        (false, false) => (Style::default().fg(app.theme.error), app.theme.error, "❌"),
+    }
+}
+
+fn version_options(app: &App) -> Vec<Span<'static>> {
+    GameVersion::ALL
+        .iter()
+        .map(|&version| create_version_span(version, app.version, &app.theme))
+        .collect()
+}
+
+fn addon_options(app: &App) -> Vec<Span<'static>> {
+    let current = current_addon_index(app);
+    app.registry
+        .configs()
+        .iter()
+        .enumerate()
+        .map(|(i, config)| create_selector_span(&config.name, Some(i) == current, &app.theme))
+        .collect()
+}
+
+fn current_addon_index(app: &App) -> Option<usize> {
+    let current = app.addon_config().ok()?;
+    app.registry
+        .configs()
+        .iter()
+        .position(|config| config == current)
+}
+
+/// Whether every option fits on one line inside the bordered `area`.
+fn selector_fits(options: &[Span], area: Rect) -> bool {
+    let separators = options.len().saturating_sub(1) * SELECTOR_SEPARATOR.len();
+    let width: usize = options.iter().map(Span::width).sum::<usize>() + separators;
+    width <= usize::from(area.width.saturating_sub(2))
+}
+
+/// All options separated by " | ", or only the current one with its position
+/// when they do not fit, e.g. "◀ ●Classic● ▶ 2/5".
+fn create_selector_line(
+    options: Vec<Span<'static>>,
+    current: Option<usize>,
+    area: Rect,
+    theme: &Theme,
+) -> Line<'static> {
+    let separator = || Span::styled(SELECTOR_SEPARATOR, Style::default().fg(theme.accent));
+
+    if selector_fits(&options, area) {
+        let mut spans = Vec::new();
+        for (i, option) in options.into_iter().enumerate() {
+            if i > 0 {
+                spans.push(separator());
+            }
+            spans.push(option);
+        }
+        return Line::from(spans);
     }
+
+    let total = options.len();
+    let index = current.unwrap_or(0);
+    let Some(option) = options.into_iter().nth(index) else {
+        return Line::default();
+    };
+    Line::from(vec![
+        Span::styled("◀ ", Style::default().fg(theme.accent)),
+        option,
+        Span::styled(
+            format!(" ▶ {}/{total}", index + 1),
+            Style::default().fg(theme.accent),
+        ),
+    ])
 }
 
-fn create_version_span<'a>(name: &'a str, current_version: &str, version_key: &str) -> Span<'a> {
-    let is_current = current_version == version_key;
+fn create_version_span(
+    version: GameVersion,
+    current_version: GameVersion,
+    theme: &Theme,
+) -> Span<'static> {
+    create_selector_span(version.label(), version == current_version, theme)
+}
+
+fn create_selector_span(name: &str, is_current: bool, theme: &Theme) -> Span<'static> {
     let text = if is_current {
-        format!("● {name} ●")
+        format!("●{name}●")
     } else {
-        format!("  {name}  ")
+        format!(" {name} ")
     };
 
     Span::styled(
         text,
         Style::default()
             .fg(if is_current {
-                Color::Yellow
+                theme.highlight
             } else {
-                Color::Gray
+                theme.muted
             })
             .add_modifier(if is_current {
                 Modifier::BOLD
@@ -235,33 +1233,27 @@ fn create_version_span<'a>(name: &'a str, current_version: &str, version_key: &s
     )
 }
 
-fn get_code_info(app: &App) -> (String, &'static str, Color) {
+fn get_code_info(app: &App) -> (String, String, Color) {
     match app.is_valid_battlenet_id() {
         true => get_valid_code_info(app),
-        false => get_invalid_code_info(),
+        false => get_invalid_code_info(&app.theme),
     }
 }
 
-fn get_valid_code_info(app: &App) -> (String, &'static str, Color) {
-    let code = app
-        .generate_code()
-        .unwrap_or_else(|_| "Invalid version".to_string());
+fn get_valid_code_info(app: &App) -> (String, String, Color) {
+    let code = app.generate_code().unwrap_or_else(|e| e.to_string());
 
-    let title = get_copy_feedback_title(app.copy_feedback);
-    (code, title, Color::Green)
+    let title = format!(
+        "🔑 Unlock Code ({} to copy)",
+        app.keymap.label(Action::Copy)
+    );
+    (code, title, app.theme.success)
 }
 
-fn get_invalid_code_info() -> (String, &'static str, Color) {
+fn get_invalid_code_info(theme: &Theme) -> (String, String, Color) {
     (
         "⚠️  Enter a valid Battle.net ID to generate unlock code".to_string(),
-        "🔑 Unlock Code",
-        Color::Yellow,
+        "🔑 Unlock Code".to_string(),
+        theme.highlight,
     )
 }
-
-fn get_copy_feedback_title(copy_feedback: Option<Instant>) -> &'static str {
-    copy_feedback
-        .filter(|&copy_time| copy_time.elapsed() < Duration::from_secs(2))
-        .map(|_| "🎉 Unlock Code (Copied to clipboard!)")
-        .unwrap_or("🔑 Unlock Code (Ctrl+C to copy)")
-}
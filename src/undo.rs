@@ -0,0 +1,73 @@
+/// Number of undo steps kept; older ones are dropped.
+pub const MAX_UNDO: usize = 100;
+
+/// The Battle.net ID input and its cursor at one point in time.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputSnapshot {
+    pub text: String,
+    pub cursor: Option<usize>,
+}
+
+/// The kind of change made to the input.
+///
+/// Consecutive `Insert` or `Delete` edits are undone together, so undo removes
+/// a whole typed word rather than one character at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditKind {
+    Insert,
+    Delete,
+    Replace,
+}
+
+/// Undo and redo stacks for the Battle.net ID input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UndoStack {
+    undo: Vec<InputSnapshot>,
+    redo: Vec<InputSnapshot>,
+    last_kind: Option<EditKind>,
+}
+
+impl UndoStack {
+    /// Records that an edit of `kind` changed the input from `before`.
+    pub fn record(&mut self, kind: EditKind, before: InputSnapshot) {
+        self.redo.clear();
+
+        let coalesce = kind != EditKind::Replace && self.last_kind == Some(kind);
+        if !coalesce {
+            self.undo.push(before);
+            if self.undo.len() > MAX_UNDO {
+                self.undo.remove(0);
+            }
+        }
+        self.last_kind = Some(kind);
+    }
+
+    /// Starts a new undo step for the next edit, e.g. after the cursor moved.
+    pub fn break_group(&mut self) {
+        self.last_kind = None;
+    }
+
+    /// Returns the input before the last edit, remembering `current` for redo.
+    pub fn undo(&mut self, current: InputSnapshot) -> Option<InputSnapshot> {
+        let previous = self.undo.pop()?;
+        self.redo.push(current);
+        self.last_kind = None;
+        Some(previous)
+    }
+
+    /// Returns the input before the last undo, remembering `current` for undo.
+    pub fn redo(&mut self, current: InputSnapshot) -> Option<InputSnapshot> {
+        let next = self.redo.pop()?;
+        self.undo.push(current);
+        self.last_kind = None;
+        Some(next)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
@@ -0,0 +1,84 @@
+use crate::error::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+/// World of Warcraft flavour an unlock code is generated for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum GameVersion {
+    #[default]
+    Retail,
+    Classic,
+    ClassicEra,
+    CataclysmClassic,
+    MopClassic,
+}
+
+impl GameVersion {
+    /// Every version, in the order Tab cycles through them.
+    pub const ALL: [GameVersion; 5] = [
+        GameVersion::Retail,
+        GameVersion::Classic,
+        GameVersion::ClassicEra,
+        GameVersion::CataclysmClassic,
+        GameVersion::MopClassic,
+    ];
+
+    /// Identifier used on the command line and in configuration files.
+    pub fn key(self) -> &'static str {
+        match self {
+            GameVersion::Retail => "retail",
+            GameVersion::Classic => "classic",
+            GameVersion::ClassicEra => "classic-era",
+            GameVersion::CataclysmClassic => "cata-classic",
+            GameVersion::MopClassic => "mop-classic",
+        }
+    }
+
+    /// Human readable name shown in the version bar.
+    pub fn label(self) -> &'static str {
+        match self {
+            GameVersion::Retail => "Retail",
+            GameVersion::Classic => "Classic",
+            GameVersion::ClassicEra => "Classic Era",
+            GameVersion::CataclysmClassic => "Cata Classic",
+            GameVersion::MopClassic => "MoP Classic",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&v| v == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+impl fmt::Display for GameVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.key())
+    }
+}
+
+impl FromStr for GameVersion {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.trim().to_ascii_lowercase().replace(['_', ' '], "-");
+
+        Self::ALL
+            .into_iter()
+            .find(|version| version.key() == normalized)
+            .ok_or_else(|| Error::InvalidVersion(s.to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for GameVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let key = String::deserialize(deserializer)?;
+        key.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for GameVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.key())
+    }
+}
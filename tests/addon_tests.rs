@@ -0,0 +1,152 @@
+use skillcapped_generator::addon::{AddonConfig, AddonRegistry};
+use skillcapped_generator::error::Error;
+use skillcapped_generator::version::GameVersion;
+
+#[test]
+fn test_builtin_registry() {
+    let registry = AddonRegistry::builtin();
+
+    let names: Vec<&str> = registry.configs().iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["WA4", "WA5"]);
+    assert_eq!(registry.get("wa4").unwrap().suffix, "ctdveirvrtdice");
+
+    for version in GameVersion::ALL {
+        let default = registry.default_for(version).unwrap();
+        assert_eq!(default.name, "WA5");
+        assert_eq!(default.suffix, "vridtcetvrdice");
+    }
+}
+
+#[test]
+fn test_from_toml_overrides_and_adds_configs() {
+    let registry = AddonRegistry::from_toml(
+        r#"
+        [[addon]]
+        name = "WA5"
+        suffix = "rotatedsuffix"
+
+        [[addon]]
+        name = "WA6"
+        suffix = "newsuffix"
+        versions = ["retail"]
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(registry.configs().len(), 3);
+    assert_eq!(registry.get("WA5").unwrap().suffix, "rotatedsuffix");
+    assert_eq!(
+        registry.get("WA6").unwrap().versions,
+        vec![GameVersion::Retail]
+    );
+    // The override does not set `default`, and the file marks no default, so WA4 is
+    // the first applicable config.
+    assert_eq!(
+        registry.default_for(GameVersion::Retail).unwrap().name,
+        "WA4"
+    );
+}
+
+#[test]
+fn test_from_toml_default_replaces_builtin_default() {
+    let registry = AddonRegistry::from_toml(
+        r#"
+        [[addon]]
+        name = "WA6"
+        suffix = "newsuffix"
+        versions = ["retail", "mop-classic"]
+        default = true
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        registry.default_for(GameVersion::Retail).unwrap().name,
+        "WA6"
+    );
+    assert_eq!(
+        registry.default_for(GameVersion::MopClassic).unwrap().name,
+        "WA6"
+    );
+    // Versions the file's default does not cover keep the built-in default.
+    assert_eq!(
+        registry.default_for(GameVersion::Classic).unwrap().name,
+        "WA5"
+    );
+}
+
+#[test]
+fn test_from_toml_version_default_keeps_builtin_default_elsewhere() {
+    let registry = AddonRegistry::from_toml(
+        r#"
+        [[addon]]
+        name = "WA4"
+        suffix = "ctdveirvrtdice"
+        versions = ["classic"]
+        default = true
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        registry.default_for(GameVersion::Classic).unwrap().name,
+        "WA4"
+    );
+    for version in [GameVersion::Retail, GameVersion::MopClassic] {
+        assert_eq!(registry.default_for(version).unwrap().name, "WA5");
+    }
+}
+
+#[test]
+fn test_from_toml_empty_file_is_builtin() {
+    assert_eq!(
+        AddonRegistry::from_toml("").unwrap(),
+        AddonRegistry::builtin()
+    );
+}
+
+#[test]
+fn test_from_toml_rejects_invalid_files() {
+    let invalid = [
+        "[[addon]]\nname = \"WA6\"",
+        "[[addon]]\nname = \"\"\nsuffix = \"abc\"",
+        "[[addon]]\nname = \"WA6\"\nsuffix = \"\"",
+        "[[addon]]\nname = \"WA6\"\nsuffix = \"abc\"\nversions = [\"tbc\"]",
+        "[addons]",
+    ];
+
+    for text in invalid {
+        assert!(
+            matches!(
+                AddonRegistry::from_toml(text),
+                Err(Error::InvalidRegistry(_))
+            ),
+            "expected {text:?} to be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_default_for_without_applicable_config() {
+    let mut registry = AddonRegistry::builtin();
+    for name in ["WA4", "WA5"] {
+        registry
+            .register(AddonConfig {
+                versions: vec![GameVersion::Retail],
+                ..AddonConfig::new(name, "suffix")
+            })
+            .unwrap();
+    }
+
+    assert_eq!(
+        registry.default_for(GameVersion::Classic),
+        Err(Error::NoAddonConfig(GameVersion::Classic))
+    );
+}
+
+#[test]
+fn test_load_from_missing_file() {
+    let result = AddonRegistry::load_from(std::path::Path::new("/nonexistent/addons.toml"));
+
+    assert!(matches!(result, Err(Error::InvalidRegistry(_))));
+}
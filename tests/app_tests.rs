@@ -1,12 +1,16 @@
-use skillcapped_generator::app::App;
+use skillcapped_generator::{
+    app::{App, Mode},
+    battletag::ValidationIssue,
+    version::GameVersion,
+};
 
 #[test]
 fn test_new_app() {
     let app = App::new();
     assert_eq!(app.battlenet_id, "");
     assert!(!app.use_lowercase);
-    assert_eq!(app.version, "retail");
-    assert!(app.copy_feedback.is_none());
+    assert_eq!(app.version, GameVersion::Retail);
+    assert!(app.status.is_none());
 }
 
 #[test]
@@ -20,13 +24,22 @@ fn test_reset_input() {
 #[test]
 fn test_toggle_version() {
     let mut app = App::new();
-    assert_eq!(app.version, "retail");
+    assert_eq!(app.version, GameVersion::Retail);
 
     app.toggle_version();
-    assert_eq!(app.version, "classic");
+    assert_eq!(app.version, GameVersion::Classic);
 
     app.toggle_version();
-    assert_eq!(app.version, "retail");
+    assert_eq!(app.version, GameVersion::ClassicEra);
+
+    app.toggle_version();
+    assert_eq!(app.version, GameVersion::CataclysmClassic);
+
+    app.toggle_version();
+    assert_eq!(app.version, GameVersion::MopClassic);
+
+    app.toggle_version();
+    assert_eq!(app.version, GameVersion::Retail);
 }
 
 #[test]
@@ -104,14 +117,14 @@ fn test_generate_code() {
     app.battlenet_id = "TestUser#1234".to_string();
 
     // Test retail version
-    app.version = "retail".to_string();
+    app.version = GameVersion::Retail;
     let result = app.generate_code();
     assert!(result.is_ok());
     let code = result.unwrap();
     assert!(!code.is_empty());
 
     // Test classic version
-    app.version = "classic".to_string();
+    app.version = GameVersion::Classic;
     let result = app.generate_code();
     assert!(result.is_ok());
     let code = result.unwrap();
@@ -124,12 +137,12 @@ fn test_generate_code() {
 }
 
 #[test]
-fn test_get_wa_configs() {
+fn test_builtin_addon_configs() {
     let app = App::new();
-    let (wa4, wa5) = app.get_wa_configs();
+    let suffix = |name| app.registry.get(name).map(|config| config.suffix.clone());
 
-    assert_eq!(wa4, "ctdveirvrtidce");
-    assert_eq!(wa5, "vridtcetvrdice");
+    assert_eq!(suffix("WA4").as_deref(), Some("ctdveirvrtdice"));
+    assert_eq!(suffix("WA5").as_deref(), Some("vridtcetvrdice"));
 }
 
 #[test]
@@ -155,27 +168,22 @@ fn test_default_implementation() {
     let app = App::default();
     assert_eq!(app.battlenet_id, "");
     assert!(!app.use_lowercase);
-    assert_eq!(app.version, "retail");
-    assert!(app.copy_feedback.is_none());
+    assert_eq!(app.version, GameVersion::Retail);
+    assert!(app.status.is_none());
 }
 
 #[test]
-fn test_toggle_version_with_invalid_version() {
-    let mut app = App::new();
-    app.version = "invalid_version".to_string();
-    app.toggle_version();
-    assert_eq!(app.version, "retail"); // Should default to retail
-}
-
-#[test]
-fn test_generate_code_with_invalid_version() {
+fn test_generate_code_for_every_version() {
     let mut app = App::new();
     app.battlenet_id = "TestUser#1234".to_string();
-    app.version = "invalid_version".to_string();
 
-    let result = app.generate_code();
-    assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), "Invalid version");
+    for version in GameVersion::ALL {
+        app.version = version;
+        assert_eq!(
+            app.generate_code().unwrap(),
+            "VGVzdFVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl"
+        );
+    }
 }
 
 #[test]
@@ -198,7 +206,7 @@ fn test_validate_code_comprehensive_matching() {
     app.battlenet_id = "TestUser#1234".to_string();
 
     // Test all possible combinations that should be valid
-    app.version = "retail".to_string();
+    app.version = GameVersion::Retail;
 
     // Test lowercase battlenet_id + config
     app.use_lowercase = true;
@@ -221,3 +229,394 @@ fn test_decode_import_string_edge_cases() {
     // This should not crash and should handle the filtering
     assert!(!result); // It won't match our battlenet_id, but should not panic
 }
+
+#[test]
+fn test_validation_issues() {
+    let mut app = App::new();
+    app.battlenet_id = "TestUser#1234".to_string();
+    assert!(app.validation_issues().is_empty());
+
+    app.battlenet_id = "TestUser".to_string();
+    assert_eq!(app.validation_issues(), vec![ValidationIssue::MissingHash]);
+}
+
+#[test]
+fn test_sanitize_input_keeps_unicode_letters() {
+    let mut app = App::new();
+    app.battlenet_id = "Ærøn!#12-34".to_string();
+    app.sanitize_input();
+    assert_eq!(app.battlenet_id, "Ærøn#1234");
+    assert!(app.is_valid_battlenet_id());
+}
+
+#[test]
+fn test_battle_tag() {
+    let mut app = App::new();
+    app.battlenet_id = "Xerrion#2624".to_string();
+    assert_eq!(app.battle_tag().unwrap().name(), "Xerrion");
+
+    app.battlenet_id = "1Xerrion#2624".to_string();
+    assert!(app.battle_tag().is_err());
+}
+
+#[test]
+fn test_toggle_mode() {
+    let mut app = App::new();
+    assert_eq!(app.mode, Mode::Generate);
+
+    app.toggle_mode();
+    assert_eq!(app.mode, Mode::Verify);
+
+    app.toggle_mode();
+    assert_eq!(app.mode, Mode::Generate);
+}
+
+#[test]
+fn test_inspect_code() {
+    let mut app = App::new();
+    app.battlenet_id = "TestUser#1234".to_string();
+    let code = app.generate_code().unwrap();
+
+    let inspection = app.inspect_code(&code).unwrap();
+    assert_eq!(inspection.battlenet_id, "TestUser#1234");
+    assert_eq!(inspection.config_name, "WA5");
+    assert!(!inspection.lowercased);
+    assert!(inspection.matches_current_id);
+
+    app.battlenet_id = "OtherUser#1234".to_string();
+    assert!(!app.inspect_code(&code).unwrap().matches_current_id);
+}
+
+#[test]
+fn test_inspect_code_lowercased_and_wa4() {
+    use base64::{engine::general_purpose, Engine as _};
+
+    let mut app = App::new();
+    app.battlenet_id = "TestUser#1234".to_string();
+    let code = general_purpose::STANDARD.encode("testuser#1234ctdveirvrtdice");
+
+    let inspection = app.inspect_code(&code).unwrap();
+    assert_eq!(inspection.battlenet_id, "testuser#1234");
+    assert_eq!(inspection.config_name, "WA4");
+    assert!(inspection.lowercased);
+    assert!(inspection.matches_current_id);
+}
+
+#[test]
+fn test_inspect_code_unknown_config() {
+    use base64::{engine::general_purpose, Engine as _};
+    use skillcapped_generator::error::Error;
+
+    let app = App::new();
+    let code = general_purpose::STANDARD.encode("TestUser#1234unknown");
+
+    assert_eq!(app.inspect_code(&code), Err(Error::ConfigMismatch));
+    assert!(app.inspect_code("not base64!").is_err());
+}
+
+#[test]
+fn test_decode_code() {
+    use base64::{engine::general_purpose, Engine as _};
+    use skillcapped_generator::error::Error;
+
+    let app = App::new();
+
+    let decoded = app
+        .decode_code("VGVzdFVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl")
+        .unwrap();
+    assert_eq!(decoded.battlenet_id, "TestUser#1234");
+    assert_eq!(decoded.config_name, "WA5");
+    assert!(!decoded.is_lowercase());
+
+    let wa4 = general_purpose::STANDARD.encode("xerrion#2624ctdveirvrtdice");
+    let decoded = app.decode_code(&wa4).unwrap();
+    assert_eq!(decoded.battlenet_id, "xerrion#2624");
+    assert_eq!(decoded.config_name, "WA4");
+    assert!(decoded.is_lowercase());
+
+    let unknown = general_purpose::STANDARD.encode("TestUser#1234");
+    assert_eq!(app.decode_code(&unknown), Err(Error::ConfigMismatch));
+
+    let no_id = general_purpose::STANDARD.encode("vridtcetvrdice");
+    assert!(matches!(
+        app.decode_code(&no_id),
+        Err(Error::InvalidBattleTag { .. })
+    ));
+}
+
+#[test]
+fn test_decode_code_keeps_legacy_names() {
+    use base64::{engine::general_purpose, Engine as _};
+
+    // Earlier releases generated codes for names the BattleTag rules now reject.
+    let app = App::new();
+    let legacy = general_purpose::STANDARD.encode("Ab#1234vridtcetvrdice");
+
+    let decoded = app.decode_code(&legacy).unwrap();
+    assert_eq!(decoded.battlenet_id, "Ab#1234");
+    assert_eq!(decoded.config_name, "WA5");
+}
+
+#[test]
+fn test_decode_code_lowercase_round_trip() {
+    let mut app = App::new();
+    app.battlenet_id = "İstanbul#1234".to_string();
+    app.use_lowercase = true;
+    let code = app.generate_code().unwrap();
+
+    // Lowercasing 'İ' adds U+0307, which the BattleTag rules do not allow.
+    let decoded = app.decode_code(&code).unwrap();
+    assert_eq!(decoded.battlenet_id, "i\u{307}stanbul#1234");
+    assert!(decoded.is_lowercase());
+    assert!(app.inspect_code(&code).unwrap().matches_current_id);
+}
+
+#[test]
+fn test_generate_and_validate_with_custom_registry() {
+    use base64::{engine::general_purpose, Engine as _};
+    use skillcapped_generator::addon::AddonRegistry;
+
+    let registry = AddonRegistry::from_toml(
+        "[[addon]]\nname = \"WA6\"\nsuffix = \"newsuffix\"\ndefault = true",
+    )
+    .unwrap();
+    let mut app = App::with_registry(registry);
+    app.battlenet_id = "TestUser#1234".to_string();
+
+    let code = app.generate_code().unwrap();
+    assert_eq!(
+        code,
+        general_purpose::STANDARD.encode("TestUser#1234newsuffix")
+    );
+    assert!(app.validate_code(&code));
+    assert_eq!(app.decode_code(&code).unwrap().config_name, "WA6");
+
+    // Codes for the built-in configs are still accepted.
+    assert!(app.validate_code("VGVzdFVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl"));
+}
+
+#[test]
+fn test_addon_selection() {
+    use skillcapped_generator::error::Error;
+
+    let mut app = App::new();
+    app.battlenet_id = "TestUser#1234".to_string();
+    assert_eq!(app.addon_config().unwrap().name, "WA5");
+
+    app.addon = Some("wa4".to_string());
+    assert_eq!(app.addon_config().unwrap().name, "WA4");
+    let code = app.generate_code().unwrap();
+    assert_eq!(code, "VGVzdFVzZXIjMTIzNGN0ZHZlaXJ2cnRkaWNl");
+    assert_eq!(app.decode_code(&code).unwrap().config_name, "WA4");
+
+    app.addon = Some("WA9".to_string());
+    assert_eq!(
+        app.generate_code(),
+        Err(Error::UnknownAddon("WA9".to_string()))
+    );
+}
+
+#[test]
+fn test_cycle_addon_wraps() {
+    let mut app = App::new();
+
+    app.cycle_addon();
+    assert_eq!(app.addon.as_deref(), Some("WA4"));
+    app.cycle_addon();
+    assert_eq!(app.addon.as_deref(), Some("WA5"));
+    app.cycle_addon();
+    assert_eq!(app.addon.as_deref(), Some("WA4"));
+}
+
+#[test]
+fn test_cursor_editing() {
+    let mut app = App::new();
+    for c in "TstUser#1234".chars() {
+        app.add_char(c);
+    }
+    assert_eq!(app.cursor, None);
+    assert_eq!(app.cursor_position(), 12);
+
+    // Fix the typo in the middle.
+    app.move_cursor_home();
+    app.move_cursor_right();
+    app.add_char('e');
+    assert_eq!(app.battlenet_id, "TestUser#1234");
+    assert_eq!(app.cursor_position(), 2);
+
+    app.move_cursor_left();
+    app.delete_char();
+    assert_eq!(app.battlenet_id, "TstUser#1234");
+    app.remove_char();
+    assert_eq!(app.battlenet_id, "stUser#1234");
+    assert_eq!(app.cursor_position(), 0);
+
+    // Backspace at the start and Left past it do nothing.
+    app.remove_char();
+    app.move_cursor_left();
+    assert_eq!(app.battlenet_id, "stUser#1234");
+    assert_eq!(app.cursor_position(), 0);
+
+    app.move_cursor_end();
+    app.delete_char();
+    assert_eq!(app.battlenet_id, "stUser#1234");
+    app.move_cursor_right();
+    assert_eq!(app.cursor, None);
+}
+
+#[test]
+fn test_cursor_editing_non_ascii() {
+    let mut app = App::new();
+    app.battlenet_id = "Ærn#1234".to_string();
+
+    app.move_cursor_home();
+    app.move_cursor_right();
+    app.add_char('ø');
+    assert_eq!(app.battlenet_id, "Æørn#1234");
+    app.remove_char();
+    app.delete_char();
+    assert_eq!(app.battlenet_id, "Æn#1234");
+}
+
+#[test]
+fn test_delete_word_and_to_start() {
+    let mut app = App::new();
+    app.battlenet_id = "TestUser#1234".to_string();
+
+    app.delete_word_before_cursor();
+    assert_eq!(app.battlenet_id, "TestUser#");
+    app.delete_word_before_cursor();
+    assert_eq!(app.battlenet_id, "");
+
+    app.battlenet_id = "TestUser#1234".to_string();
+    app.cursor = Some(4);
+    app.delete_to_start();
+    assert_eq!(app.battlenet_id, "User#1234");
+    assert_eq!(app.cursor_position(), 0);
+}
+
+#[test]
+fn test_sanitize_input_keeps_cursor_position() {
+    let mut app = App::new();
+    app.battlenet_id = "Test#1234".to_string();
+    app.cursor = Some(4);
+    app.add_char(' ');
+    app.add_char('U');
+
+    app.sanitize_input();
+
+    assert_eq!(app.battlenet_id, "TestU#1234");
+    assert_eq!(app.cursor_position(), 5);
+}
+
+#[test]
+fn test_undo_redo_input_edits() {
+    let mut app = App::new();
+    for c in "TestUser#1234".chars() {
+        app.add_char(c);
+    }
+
+    app.reset_input();
+    assert_eq!(app.battlenet_id, "");
+    app.undo();
+    assert_eq!(app.battlenet_id, "TestUser#1234");
+
+    app.remove_char();
+    app.remove_char();
+    assert_eq!(app.battlenet_id, "TestUser#12");
+    app.undo();
+    assert_eq!(app.battlenet_id, "TestUser#1234");
+
+    // Typing is undone as one step.
+    app.undo();
+    assert_eq!(app.battlenet_id, "");
+    app.undo();
+    assert_eq!(app.battlenet_id, "");
+
+    app.redo();
+    assert_eq!(app.battlenet_id, "TestUser#1234");
+    app.redo();
+    assert_eq!(app.battlenet_id, "TestUser#12");
+}
+
+#[test]
+fn test_undo_paste_restores_previous_id_and_cursor() {
+    let mut app = App::new();
+    app.battlenet_id = "Xerrion#2624".to_string();
+    app.cursor = Some(3);
+
+    app.replace_input(" Other User#9999 ");
+    assert_eq!(app.battlenet_id, "OtherUser#9999");
+    assert_eq!(app.cursor, None);
+
+    app.undo();
+    assert_eq!(app.battlenet_id, "Xerrion#2624");
+    assert_eq!(app.cursor, Some(3));
+}
+
+#[test]
+fn test_cursor_movement_splits_undo_steps() {
+    let mut app = App::new();
+    for c in "Test#1234".chars() {
+        app.add_char(c);
+    }
+    app.move_cursor_home();
+    app.add_char('X');
+
+    app.undo();
+    assert_eq!(app.battlenet_id, "Test#1234");
+}
+
+#[test]
+fn test_paste_battlenet_id_extracts_single_tag() {
+    let mut app = App::new();
+
+    app.paste_battlenet_id("Hi, my tag is Foo#1234!");
+
+    assert_eq!(app.battlenet_id, "Foo#1234");
+    assert_eq!(app.mode, Mode::Generate);
+    app.undo();
+    assert_eq!(app.battlenet_id, "");
+}
+
+#[test]
+fn test_paste_battlenet_id_without_tag_sanitizes() {
+    let mut app = App::new();
+
+    app.paste_battlenet_id("Test User!");
+
+    assert_eq!(app.battlenet_id, "TestUser");
+}
+
+#[test]
+fn test_paste_battlenet_id_with_several_tags_offers_a_choice() {
+    let mut app = App::new();
+    app.battlenet_id = "Old#1234".to_string();
+
+    app.paste_battlenet_id("Foo#1234 or Bar#5678?");
+
+    assert_eq!(app.mode, Mode::PasteSelection);
+    assert_eq!(app.paste_candidates.len(), 2);
+    assert_eq!(app.battlenet_id, "Old#1234");
+
+    app.select_next_paste_candidate();
+    app.select_next_paste_candidate();
+    assert_eq!(app.paste_selected, 1);
+    app.use_selected_paste_candidate();
+
+    assert_eq!(app.battlenet_id, "Bar#5678");
+    assert_eq!(app.mode, Mode::Generate);
+    assert!(app.paste_candidates.is_empty());
+}
+
+#[test]
+fn test_cancel_paste_selection_keeps_input() {
+    let mut app = App::new();
+    app.battlenet_id = "Old#1234".to_string();
+    app.paste_battlenet_id("Foo#1234 Bar#5678");
+
+    app.cancel_paste_selection();
+
+    assert_eq!(app.battlenet_id, "Old#1234");
+    assert_eq!(app.mode, Mode::Generate);
+}
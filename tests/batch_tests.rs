@@ -0,0 +1,103 @@
+use skillcapped_generator::app::App;
+use skillcapped_generator::batch::{generate_batch, BatchSummary};
+use skillcapped_generator::version::GameVersion;
+
+fn generator(version: GameVersion, use_lowercase: bool) -> App {
+    let mut app = App::new();
+    app.version = version;
+    app.use_lowercase = use_lowercase;
+    app
+}
+
+#[test]
+fn test_generate_batch_valid_ids() {
+    let input = "TestUser#1234\nXerrion#2624\n";
+    let entries = generate_batch(input.as_bytes(), &generator(GameVersion::Retail, false)).unwrap();
+
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].record.input, "TestUser#1234");
+    assert_eq!(
+        entries[0].record.code.as_deref(),
+        Some("VGVzdFVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl")
+    );
+    assert_eq!(entries[1].line_number, 2);
+    assert!(entries[1].is_valid());
+}
+
+#[test]
+fn test_generate_batch_continues_past_invalid_lines() {
+    let input = "invalid\nTestUser#1234\nTest#12\n";
+    let entries = generate_batch(input.as_bytes(), &generator(GameVersion::Retail, false)).unwrap();
+
+    assert_eq!(entries.len(), 3);
+    assert!(!entries[0].is_valid());
+    assert!(entries[1].is_valid());
+    assert!(!entries[2].is_valid());
+    assert_eq!(entries[2].line_number, 3);
+}
+
+#[test]
+fn test_generate_batch_skips_blank_lines_and_trims() {
+    let input = "\n   \n  TestUser#1234  \r\n\n";
+    let entries = generate_batch(input.as_bytes(), &generator(GameVersion::Retail, false)).unwrap();
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].line_number, 3);
+    assert_eq!(entries[0].record.input, "TestUser#1234");
+}
+
+#[test]
+fn test_generate_batch_reports_lines_that_are_not_utf8() {
+    // "Ærøn#1234" saved as Latin-1
+    let mut input = b"TestUser#1234\n".to_vec();
+    input.extend_from_slice(b"\xc6r\xf8n#1234\n");
+    input.extend_from_slice(b"Xerrion#2624\n");
+    let entries = generate_batch(input.as_slice(), &generator(GameVersion::Retail, false)).unwrap();
+
+    assert_eq!(entries.len(), 3);
+    assert!(entries[0].is_valid());
+    assert!(!entries[1].is_valid());
+    assert_eq!(entries[1].line_number, 2);
+    assert_eq!(entries[1].record.input, "\u{fffd}r\u{fffd}n#1234");
+    assert_eq!(entries[1].record.errors, ["line is not valid UTF-8"]);
+    assert!(entries[2].is_valid());
+}
+
+#[test]
+fn test_generate_batch_ignores_byte_order_mark() {
+    let input = "\u{feff}TestUser#1234\r\nXerrion#2624\r\n";
+    let entries = generate_batch(input.as_bytes(), &generator(GameVersion::Retail, false)).unwrap();
+
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].record.input, "TestUser#1234");
+    assert!(entries[0].is_valid());
+    assert!(entries[1].is_valid());
+}
+
+#[test]
+fn test_generate_batch_lowercase() {
+    let entries = generate_batch(
+        "TestUser#1234".as_bytes(),
+        &generator(GameVersion::Classic, true),
+    )
+    .unwrap();
+
+    assert_eq!(
+        entries[0].record.code.as_deref(),
+        Some("dGVzdHVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl")
+    );
+}
+
+#[test]
+fn test_batch_summary() {
+    let input = "TestUser#1234\ninvalid\nXerrion#2624\n";
+    let entries = generate_batch(input.as_bytes(), &generator(GameVersion::Retail, false)).unwrap();
+
+    assert_eq!(
+        BatchSummary::from_entries(&entries),
+        BatchSummary {
+            generated: 2,
+            invalid: 1
+        }
+    );
+}
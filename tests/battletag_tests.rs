@@ -0,0 +1,207 @@
+use skillcapped_generator::{
+    battletag::{extract, validate, BattleTag, ValidationIssue},
+    error::Error,
+};
+
+#[test]
+fn test_validate_valid_ids() {
+    assert!(validate("TestUser#1234").is_empty());
+    assert!(validate("Xerrion#2624").is_empty());
+    assert!(validate("Abc#1234").is_empty());
+}
+
+#[test]
+fn test_validate_empty() {
+    assert_eq!(validate(""), vec![ValidationIssue::Empty]);
+}
+
+#[test]
+fn test_validate_missing_hash() {
+    assert_eq!(validate("TestUser"), vec![ValidationIssue::MissingHash]);
+    assert_eq!(
+        validate("Te"),
+        vec![ValidationIssue::MissingHash, ValidationIssue::NameTooShort]
+    );
+}
+
+#[test]
+fn test_validate_multiple_hashes() {
+    assert_eq!(
+        validate("Test#User#1234"),
+        vec![ValidationIssue::MultipleHashes]
+    );
+}
+
+#[test]
+fn test_validate_name_length() {
+    assert_eq!(validate("#1234"), vec![ValidationIssue::EmptyName]);
+    assert_eq!(validate("Te#1234"), vec![ValidationIssue::NameTooShort]);
+    assert_eq!(
+        validate("ThirteenChars#1234"),
+        vec![ValidationIssue::NameTooLong]
+    );
+    assert!(validate("TwelveChars1#1234").is_empty());
+}
+
+#[test]
+fn test_validate_invalid_name_character() {
+    assert_eq!(
+        validate("Test@User#1234"),
+        vec![ValidationIssue::InvalidNameCharacter('@')]
+    );
+}
+
+#[test]
+fn test_validate_discriminator() {
+    assert_eq!(
+        validate("TestUser#"),
+        vec![ValidationIssue::DiscriminatorTooShort]
+    );
+    assert_eq!(
+        validate("TestUser#123"),
+        vec![ValidationIssue::DiscriminatorTooShort]
+    );
+    assert_eq!(
+        validate("TestUser#12a4"),
+        vec![ValidationIssue::NonDigitDiscriminator]
+    );
+    assert_eq!(
+        validate("TestUser#1a"),
+        vec![
+            ValidationIssue::NonDigitDiscriminator,
+            ValidationIssue::DiscriminatorTooShort
+        ]
+    );
+}
+
+#[test]
+fn test_validate_reports_name_and_number_issues_together() {
+    assert_eq!(
+        validate("Te#12"),
+        vec![
+            ValidationIssue::NameTooShort,
+            ValidationIssue::DiscriminatorTooShort
+        ]
+    );
+}
+
+#[test]
+fn test_issue_messages() {
+    assert_eq!(
+        ValidationIssue::MissingHash.to_string(),
+        "missing '#' between name and number"
+    );
+    assert_eq!(
+        ValidationIssue::NameTooShort.to_string(),
+        "name must be at least 3 characters"
+    );
+    assert_eq!(
+        ValidationIssue::DiscriminatorTooShort.to_string(),
+        "number after '#' must be at least 4 digits"
+    );
+}
+
+#[test]
+fn test_validate_unicode_names() {
+    assert!(validate("Ærøn#1234").is_empty());
+    assert!(validate("Élodie#12345").is_empty());
+    assert!(validate("Дмитрий#1234").is_empty());
+    assert!(validate("전사님#1234").is_empty());
+}
+
+#[test]
+fn test_validate_name_must_not_start_with_digit() {
+    assert_eq!(
+        validate("1Test#1234"),
+        vec![ValidationIssue::NameStartsWithDigit]
+    );
+    assert!(validate("Test1#1234").is_empty());
+}
+
+#[test]
+fn test_validate_name_length_counts_characters() {
+    assert!(validate("Ååå#1234").is_empty());
+    assert_eq!(
+        validate("Ååååååååååååå#1234"),
+        vec![ValidationIssue::NameTooLong]
+    );
+}
+
+#[test]
+fn test_validate_rejects_symbols_and_spaces() {
+    assert_eq!(
+        validate("Test User#1234"),
+        vec![ValidationIssue::InvalidNameCharacter(' ')]
+    );
+    assert_eq!(
+        validate("Test_User#1234"),
+        vec![ValidationIssue::InvalidNameCharacter('_')]
+    );
+}
+
+#[test]
+fn test_validate_discriminator_too_long() {
+    assert!(validate("TestUser#123456").is_empty());
+    assert_eq!(
+        validate("TestUser#1234567"),
+        vec![ValidationIssue::DiscriminatorTooLong]
+    );
+}
+
+#[test]
+fn test_battletag_parse() {
+    let tag = BattleTag::parse("Ærøn#1234").unwrap();
+    assert_eq!(tag.name(), "Ærøn");
+    assert_eq!(tag.number(), "1234");
+    assert_eq!(tag.to_string(), "Ærøn#1234");
+
+    assert_eq!(
+        BattleTag::parse("1Test#12"),
+        Err(vec![
+            ValidationIssue::NameStartsWithDigit,
+            ValidationIssue::DiscriminatorTooShort
+        ])
+    );
+}
+
+#[test]
+fn test_battletag_from_str() {
+    let tag: BattleTag = "TestUser#1234".parse().unwrap();
+    assert_eq!(tag.name(), "TestUser");
+
+    let error = "TestUser".parse::<BattleTag>().unwrap_err();
+    assert_eq!(
+        error,
+        Error::InvalidBattleTag {
+            reason: "missing '#' between name and number".to_string()
+        }
+    );
+}
+
+fn extracted(text: &str) -> Vec<String> {
+    extract(text).iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn test_extract_from_sentence() {
+    assert_eq!(extracted("Hi, my tag is Foo#1234!"), ["Foo#1234"]);
+    assert_eq!(extracted("  Xerrion#2624\n"), ["Xerrion#2624"]);
+    assert_eq!(extracted("(Ærøn#12345)"), ["Ærøn#12345"]);
+}
+
+#[test]
+fn test_extract_several_tags_in_order_without_duplicates() {
+    assert_eq!(
+        extracted("Foo#1234, Bar#5678 and Foo#1234 again"),
+        ["Foo#1234", "Bar#5678"]
+    );
+}
+
+#[test]
+fn test_extract_skips_invalid_tokens() {
+    assert!(extracted("").is_empty());
+    assert!(extracted("no tag here").is_empty());
+    assert!(extracted("#1234 Fo#1234 Foo#12 Foo#1234567").is_empty());
+    assert!(extracted("ThisNameIsTooLong#1234").is_empty());
+    assert!(extracted("Foo#1234abc 1Foo#1234").is_empty());
+}
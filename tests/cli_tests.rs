@@ -0,0 +1,307 @@
+use clap::Parser;
+use skillcapped_generator::addon::AddonRegistry;
+use skillcapped_generator::cli::{
+    run_batch, run_with_registry, Cli, Command, EXIT_FAILURE, EXIT_INVALID_CODE, EXIT_INVALID_ID,
+    EXIT_SUCCESS, EXIT_USAGE,
+};
+use skillcapped_generator::clipboard::ClipboardKind;
+use skillcapped_generator::theme::ThemeName;
+use skillcapped_generator::version::GameVersion;
+
+fn run_cli(args: &[&str]) -> (u8, String, String) {
+    let cli = Cli::try_parse_from(args).unwrap();
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+    // The built-in registry keeps the expected codes independent of a local addons.toml
+    let registry = AddonRegistry::builtin();
+    let code = run_with_registry(&cli, &registry, &mut out, &mut err);
+    (
+        code,
+        String::from_utf8(out).unwrap(),
+        String::from_utf8(err).unwrap(),
+    )
+}
+
+#[test]
+fn test_parse_defaults() {
+    let cli = Cli::try_parse_from(["skillcapped-generator"]).unwrap();
+    assert!(cli.id.is_none());
+    assert_eq!(cli.game_version, GameVersion::Retail);
+    assert!(!cli.lowercase);
+    assert!(!cli.copy);
+    assert!(!cli.quiet);
+    assert!(cli.is_interactive());
+}
+
+#[test]
+fn test_parse_all_options() {
+    let cli = Cli::try_parse_from([
+        "skillcapped-generator",
+        "--id",
+        "TestUser#1234",
+        "--version",
+        "classic",
+        "--lowercase",
+        "--copy",
+        "--quiet",
+    ])
+    .unwrap();
+
+    assert_eq!(cli.id.as_deref(), Some("TestUser#1234"));
+    assert_eq!(cli.game_version, GameVersion::Classic);
+    assert!(cli.lowercase);
+    assert!(cli.copy);
+    assert!(cli.quiet);
+    assert!(!cli.is_interactive());
+}
+
+#[test]
+fn test_parse_rejects_unknown_version() {
+    let result = Cli::try_parse_from(["skillcapped-generator", "--version", "tbc"]);
+    assert!(result.is_err());
+
+    let cli = Cli::try_parse_from(["skillcapped-generator", "--version", "mop-classic"]).unwrap();
+    assert_eq!(cli.game_version, GameVersion::MopClassic);
+}
+
+#[test]
+fn test_parse_clipboard() {
+    let cli = Cli::try_parse_from(["skillcapped-generator", "--id", "Foo#1234", "--copy"]).unwrap();
+    assert_eq!(cli.clipboard, ClipboardKind::Auto);
+
+    let cli = Cli::try_parse_from([
+        "skillcapped-generator",
+        "--id",
+        "Foo#1234",
+        "--copy",
+        "--clipboard",
+        "osc52",
+    ])
+    .unwrap();
+    assert_eq!(cli.clipboard, ClipboardKind::Osc52);
+
+    // A backend without --copy is a usage error
+    let result = Cli::try_parse_from(["skillcapped-generator", "--clipboard", "xclip"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_parse_theme() {
+    let cli = Cli::try_parse_from(["skillcapped-generator"]).unwrap();
+    assert!(cli.theme.is_none());
+
+    let cli = Cli::try_parse_from(["skillcapped-generator", "--theme", "high-contrast"]).unwrap();
+    assert_eq!(cli.theme, Some(ThemeName::HighContrast));
+    assert!(cli.is_interactive());
+
+    let result = Cli::try_parse_from(["skillcapped-generator", "--theme", "solarized"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_run_prints_code_on_stdout() {
+    let (code, out, err) = run_cli(&["skillcapped-generator", "--id", "TestUser#1234"]);
+
+    assert_eq!(code, EXIT_SUCCESS);
+    assert_eq!(out, "VGVzdFVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl\n");
+    assert!(err.is_empty());
+}
+
+#[test]
+fn test_run_lowercase() {
+    let (code, out, _) = run_cli(&["skillcapped-generator", "-i", "TestUser#1234", "-l"]);
+
+    assert_eq!(code, EXIT_SUCCESS);
+    assert_eq!(out, "dGVzdHVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl\n");
+}
+
+#[test]
+fn test_run_invalid_id() {
+    let (code, out, err) = run_cli(&["skillcapped-generator", "--id", "invalid"]);
+
+    assert_eq!(code, EXIT_INVALID_ID);
+    assert!(out.is_empty());
+    assert!(err.contains("invalid Battle.net ID"));
+}
+
+fn run_batch_cli(args: &[&str], input: &str) -> (u8, String, String) {
+    let cli = Cli::try_parse_from(args).unwrap();
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+    let registry = AddonRegistry::builtin();
+    let code = run_batch(&cli, &registry, input.as_bytes(), &mut out, &mut err);
+    (
+        code,
+        String::from_utf8(out).unwrap(),
+        String::from_utf8(err).unwrap(),
+    )
+}
+
+#[test]
+fn test_parse_batch_conflicts_with_id() {
+    let result = Cli::try_parse_from(["skillcapped-generator", "--batch", "-", "--id", "A#1234"]);
+    assert!(result.is_err());
+
+    let cli = Cli::try_parse_from(["skillcapped-generator", "--batch", "ids.txt"]).unwrap();
+    assert!(!cli.is_interactive());
+}
+
+#[test]
+fn test_run_batch_reports_invalid_lines() {
+    let (code, out, err) = run_batch_cli(
+        &["skillcapped-generator", "--batch", "-"],
+        "TestUser#1234\ninvalid\n",
+    );
+
+    assert_eq!(code, EXIT_INVALID_ID);
+    assert_eq!(out, "TestUser#1234\tVGVzdFVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl\n");
+    assert!(err.contains("line 2: 'invalid'"));
+    assert!(err.contains("Summary: 1 generated, 1 invalid"));
+}
+
+#[test]
+fn test_run_batch_quiet_success() {
+    let (code, out, err) = run_batch_cli(
+        &["skillcapped-generator", "--batch", "-", "--quiet"],
+        "TestUser#1234\nXerrion#2624\n",
+    );
+
+    assert_eq!(code, EXIT_SUCCESS);
+    assert_eq!(out.lines().count(), 2);
+    assert!(err.is_empty());
+}
+
+#[test]
+fn test_run_batch_missing_file() {
+    let (code, out, err) = run_cli(&[
+        "skillcapped-generator",
+        "--batch",
+        "/nonexistent/skillcapped-ids.txt",
+    ]);
+
+    assert_eq!(code, EXIT_FAILURE);
+    assert!(out.is_empty());
+    assert!(err.contains("could not read"));
+}
+
+#[test]
+fn test_run_json_format_includes_errors() {
+    let (code, out, _) = run_cli(&["skillcapped-generator", "-i", "invalid", "-f", "json"]);
+
+    assert_eq!(code, EXIT_INVALID_ID);
+    let record: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(record["input"], "invalid");
+    assert!(record["code"].is_null());
+    assert!(!record["errors"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_run_batch_csv_format() {
+    let (code, out, _) = run_batch_cli(
+        &["skillcapped-generator", "-b", "-", "-f", "csv", "-q"],
+        "TestUser#1234\n",
+    );
+
+    assert_eq!(code, EXIT_SUCCESS);
+    assert_eq!(out.lines().count(), 2);
+    assert!(out.starts_with("input,normalized_id,version,lowercase,code,errors\n"));
+}
+
+#[test]
+fn test_parse_decode_subcommand() {
+    let cli =
+        Cli::try_parse_from(["skillcapped-generator", "decode", "abc", "-f", "json"]).unwrap();
+
+    assert!(matches!(cli.command, Some(Command::Decode { ref code }) if code == "abc"));
+    assert_eq!(
+        cli.format,
+        skillcapped_generator::output::OutputFormat::Json
+    );
+    assert!(!cli.is_interactive());
+}
+
+#[test]
+fn test_run_decode() {
+    let (code, out, err) = run_cli(&[
+        "skillcapped-generator",
+        "decode",
+        "VGVzdFVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl",
+    ]);
+
+    assert_eq!(code, EXIT_SUCCESS);
+    assert_eq!(out, "TestUser#1234\tWA5\n");
+    assert!(err.is_empty());
+}
+
+#[test]
+fn test_run_decode_json() {
+    let (code, out, _) = run_cli(&[
+        "skillcapped-generator",
+        "decode",
+        "dGVzdHVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl",
+        "--format",
+        "json",
+    ]);
+
+    assert_eq!(code, EXIT_SUCCESS);
+    let record: serde_json::Value = serde_json::from_str(&out).unwrap();
+    assert_eq!(record["battlenet_id"], "testuser#1234");
+    assert_eq!(record["config"], "WA5");
+    assert_eq!(record["lowercase"], true);
+}
+
+#[test]
+fn test_run_decode_unknown_suffix() {
+    let (code, out, err) = run_cli(&["skillcapped-generator", "decode", "SGVsbG8gV29ybGQ="]);
+
+    assert_eq!(code, EXIT_INVALID_CODE);
+    assert!(out.is_empty());
+    assert!(err.contains("does not match any known addon configuration"));
+}
+
+#[test]
+fn test_run_with_custom_registry() {
+    let cli = Cli::try_parse_from(["skillcapped-generator", "--id", "TestUser#1234"]).unwrap();
+    let registry =
+        AddonRegistry::from_toml("[[addon]]\nname = \"WA5\"\nsuffix = \"rotated\"\ndefault = true")
+            .unwrap();
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+
+    let code = run_with_registry(&cli, &registry, &mut out, &mut err);
+
+    assert_eq!(code, EXIT_SUCCESS);
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "VGVzdFVzZXIjMTIzNHJvdGF0ZWQ=\n"
+    );
+}
+
+#[test]
+fn test_run_single_with_addon() {
+    let (code, out, _) = run_cli(&[
+        "skillcapped-generator",
+        "--id",
+        "TestUser#1234",
+        "--addon",
+        "WA4",
+    ]);
+
+    assert_eq!(code, EXIT_SUCCESS);
+    assert_eq!(out, "VGVzdFVzZXIjMTIzNGN0ZHZlaXJ2cnRkaWNl\n");
+}
+
+#[test]
+fn test_run_unknown_addon_is_usage_error() {
+    let (code, out, err) = run_cli(&[
+        "skillcapped-generator",
+        "--id",
+        "TestUser#1234",
+        "-a",
+        "WA9",
+    ]);
+
+    assert_eq!(code, EXIT_USAGE);
+    assert!(out.is_empty());
+    assert!(err.contains("unknown addon configuration: 'WA9' (available: WA4, WA5)"));
+}
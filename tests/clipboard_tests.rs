@@ -0,0 +1,94 @@
+use skillcapped_generator::{
+    clipboard::{
+        detect, detect_persistent, osc52_sequence, ClipboardBackend, ClipboardKind,
+        MemoryClipboard, Osc52Clipboard,
+    },
+    error::Error,
+    settings::Settings,
+};
+
+#[test]
+fn test_detect_prefers_system_clipboard() {
+    let kind = detect(true, |_| true, |_| true);
+
+    assert_eq!(kind, ClipboardKind::System);
+}
+
+#[test]
+fn test_detect_clipboard_commands() {
+    let wayland = detect(false, |name| name == "WAYLAND_DISPLAY", |_| true);
+    let x11 = detect(false, |name| name == "DISPLAY", |_| true);
+    let x11_without_xclip = detect(false, |name| name == "DISPLAY", |name| name != "xclip");
+
+    assert_eq!(wayland, ClipboardKind::WlCopy);
+    assert_eq!(x11, ClipboardKind::Xclip);
+    assert_eq!(x11_without_xclip, ClipboardKind::Osc52);
+}
+
+#[test]
+fn test_detect_falls_back_to_osc52_over_ssh() {
+    let kind = detect(false, |name| name == "SSH_TTY", |_| false);
+
+    assert_eq!(kind, ClipboardKind::Osc52);
+}
+
+#[test]
+fn test_detect_persistent_prefers_clipboard_commands() {
+    let wayland = detect_persistent(true, |name| name == "WAYLAND_DISPLAY", |_| true);
+    let x11 = detect_persistent(true, |name| name == "DISPLAY", |_| true);
+    let without_commands = detect_persistent(true, |name| name == "DISPLAY", |_| false);
+    let headless = detect_persistent(false, |_| false, |_| true);
+
+    assert_eq!(wayland, ClipboardKind::WlCopy);
+    assert_eq!(x11, ClipboardKind::Xclip);
+    assert_eq!(without_commands, ClipboardKind::System);
+    assert_eq!(headless, ClipboardKind::Osc52);
+}
+
+#[test]
+fn test_osc52_sequence() {
+    assert_eq!(
+        osc52_sequence("TestUser#1234"),
+        "\x1b]52;c;VGVzdFVzZXIjMTIzNA==\x07"
+    );
+}
+
+#[test]
+fn test_osc52_clipboard_writes_to_terminal() {
+    let mut clipboard = Osc52Clipboard::new(Vec::new());
+
+    clipboard.set_text("abc").unwrap();
+
+    assert_eq!(clipboard.kind(), ClipboardKind::Osc52);
+    assert!(matches!(clipboard.get_text(), Err(Error::Clipboard(_))));
+    assert_eq!(clipboard.into_inner(), b"\x1b]52;c;YWJj\x07");
+}
+
+#[test]
+fn test_clipboard_kind_in_settings() {
+    let settings = Settings::from_toml("clipboard = \"osc52\"").unwrap();
+    assert_eq!(settings.clipboard, ClipboardKind::Osc52);
+
+    let settings = Settings::from_toml("clipboard = \"wl-copy\"").unwrap();
+    assert_eq!(settings.clipboard, ClipboardKind::WlCopy);
+
+    assert_eq!(Settings::default().clipboard, ClipboardKind::Auto);
+    assert!(Settings::from_toml("clipboard = \"pbcopy\"").is_err());
+}
+
+#[test]
+fn test_clipboard_kind_label() {
+    assert_eq!(ClipboardKind::System.to_string(), "system");
+    assert_eq!(ClipboardKind::Osc52.to_string(), "OSC 52");
+    assert_eq!(ClipboardKind::Xclip.label(), "xclip");
+}
+
+#[test]
+fn test_memory_clipboard() {
+    let mut clipboard = MemoryClipboard::default();
+    assert!(matches!(clipboard.get_text(), Err(Error::Clipboard(_))));
+
+    clipboard.set_text("VGVzdA==").unwrap();
+    assert_eq!(clipboard.get_text().unwrap(), "VGVzdA==");
+    assert_eq!(clipboard.text.as_deref(), Some("VGVzdA=="));
+}
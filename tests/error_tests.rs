@@ -0,0 +1,82 @@
+use base64::{engine::general_purpose, Engine as _};
+use skillcapped_generator::{app::App, error::Error, version::GameVersion};
+use std::error::Error as _;
+
+#[test]
+fn test_generate_code_invalid_battletag() {
+    let mut app = App::new();
+    app.battlenet_id = "invalid".to_string();
+
+    let error = app.generate_code().unwrap_err();
+    assert!(matches!(error, Error::InvalidBattleTag { .. }));
+    assert!(error.to_string().starts_with("invalid Battle.net ID"));
+}
+
+#[test]
+fn test_invalid_version_error() {
+    let error = "tbc".parse::<GameVersion>().unwrap_err();
+    assert_eq!(error, Error::InvalidVersion("tbc".to_string()));
+    assert_eq!(error.to_string(), "invalid version: 'tbc'");
+}
+
+#[test]
+fn test_decode_import_string_base64_error() {
+    let app = App::new();
+
+    let error = app.decode_import_string("abc").unwrap_err();
+    assert!(matches!(error, Error::Base64(_)));
+    assert!(error.source().is_some());
+}
+
+#[test]
+fn test_decode_import_string_utf8_error() {
+    let app = App::new();
+
+    let error = app.decode_import_string("gA==").unwrap_err();
+    assert!(matches!(error, Error::Utf8(_)));
+    assert!(error.source().is_some());
+}
+
+#[test]
+fn test_decode_import_string_success() {
+    let app = App::new();
+
+    assert_eq!(
+        app.decode_import_string("SGVsbG8gV29ybGQ="),
+        Ok("Hello World".to_string())
+    );
+}
+
+#[test]
+fn test_verify_code_config_mismatch() {
+    let mut app = App::new();
+    app.battlenet_id = "TestUser#1234".to_string();
+
+    let wrong_content = general_purpose::STANDARD.encode("TestUser#1234unknown");
+    assert_eq!(app.verify_code(&wrong_content), Err(Error::ConfigMismatch));
+    assert!(Error::ConfigMismatch.source().is_none());
+
+    let code = app.generate_code().unwrap();
+    assert_eq!(app.verify_code(&code), Ok(()));
+}
+
+#[test]
+fn test_error_is_std_error() {
+    let boxed: Box<dyn std::error::Error> = Box::new(Error::ConfigMismatch);
+    assert_eq!(
+        boxed.to_string(),
+        "unlock code does not match any known addon configuration"
+    );
+}
+
+#[test]
+fn test_registry_error_messages() {
+    assert_eq!(
+        Error::InvalidRegistry("addon name is empty".to_string()).to_string(),
+        "invalid addon registry: addon name is empty"
+    );
+    assert_eq!(
+        Error::NoAddonConfig(GameVersion::Classic).to_string(),
+        "no addon configuration registered for classic"
+    );
+}
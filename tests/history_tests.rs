@@ -0,0 +1,123 @@
+use skillcapped_generator::{
+    app::{App, Mode},
+    history::{unix_seconds, History, HistoryEntry, HISTORY_FILE_NAME, MAX_HISTORY},
+    profile::Profile,
+    version::GameVersion,
+};
+use std::{
+    fs,
+    time::{Duration, SystemTime},
+};
+
+fn entry(code: &str, generated_at: u64) -> HistoryEntry {
+    HistoryEntry {
+        code: code.to_string(),
+        battlenet_id: "TestUser#1234".to_string(),
+        version: GameVersion::Retail,
+        config: "WA5".to_string(),
+        generated_at,
+    }
+}
+
+#[test]
+fn test_history_record_newest_first_and_deduplicates() {
+    let mut history = History::default();
+    history.record(entry("a", 1));
+    history.record(entry("b", 2));
+    history.record(entry("a", 3));
+
+    let codes: Vec<&str> = history.entries().map(|e| e.code.as_str()).collect();
+    assert_eq!(codes, ["a", "b"]);
+    assert_eq!(history.get(0).unwrap().generated_at, 3);
+}
+
+#[test]
+fn test_history_is_bounded() {
+    let mut history = History::default();
+    for i in 0..MAX_HISTORY + 5 {
+        history.record(entry(&i.to_string(), i as u64));
+    }
+
+    assert_eq!(history.len(), MAX_HISTORY);
+    assert_eq!(history.get(0).unwrap().code, (MAX_HISTORY + 4).to_string());
+}
+
+#[test]
+fn test_history_age_label() {
+    let now = SystemTime::now();
+    let at = |seconds_ago: u64| entry("a", unix_seconds(now - Duration::from_secs(seconds_ago)));
+
+    assert_eq!(at(3).age_label(now), "just now");
+    assert_eq!(at(42).age_label(now), "42s ago");
+    assert_eq!(at(5 * 60 + 10).age_label(now), "5m ago");
+    assert_eq!(at(2 * 3600).age_label(now), "2h ago");
+    assert_eq!(at(3 * 86400).age_label(now), "3d ago");
+}
+
+#[test]
+fn test_history_save_and_load() {
+    let dir = std::env::temp_dir().join(format!(
+        "skillcapped-generator-history-{}",
+        std::process::id()
+    ));
+    let path = dir.join(HISTORY_FILE_NAME);
+    assert!(History::load_from(&path).unwrap().is_empty());
+
+    let mut history = History::default();
+    history.record(entry("a", 1));
+    history.record(entry("b", 2));
+    history.save_to(&path).unwrap();
+
+    assert_eq!(History::load_from(&path).unwrap(), history);
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn test_copy_target_by_mode() {
+    let mut app = App::new();
+    app.battlenet_id = "TestUser#1234".to_string();
+    app.use_lowercase = true;
+
+    let generated = app.copy_target().unwrap();
+    assert_eq!(generated.code, "dGVzdHVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl");
+    assert_eq!(generated.battlenet_id, "testuser#1234");
+    assert_eq!(generated.config, "WA5");
+
+    app.profiles
+        .add(Profile {
+            label: "Main".to_string(),
+            battle_tag: "Xerrion#2624".to_string(),
+            version: GameVersion::Classic,
+            lowercase: false,
+        })
+        .unwrap();
+    app.mode = Mode::Profiles;
+    let profile = app.copy_target().unwrap();
+    assert_eq!(profile.battlenet_id, "Xerrion#2624");
+    assert_eq!(profile.version, GameVersion::Classic);
+
+    app.mode = Mode::History;
+    assert!(app.copy_target().is_err());
+    app.record_history(profile.clone()).unwrap();
+    assert_eq!(app.copy_target().unwrap(), profile);
+}
+
+#[test]
+fn test_history_selection() {
+    let mut app = App::new();
+    app.history.record(entry("a", 1));
+    app.history.record(entry("b", 2));
+
+    app.toggle_history();
+    assert_eq!(app.mode, Mode::History);
+    app.select_previous_history_entry();
+    assert_eq!(app.history_selected, 0);
+    app.select_next_history_entry();
+    app.select_next_history_entry();
+    assert_eq!(app.history_selected, 1);
+    assert_eq!(app.copy_target().unwrap().code, "a");
+
+    app.toggle_history();
+    assert_eq!(app.mode, Mode::Generate);
+    assert_eq!(app.history_selected, 0);
+}
@@ -1,5 +1,29 @@
-use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
-use skillcapped_generator::{app::App, input::handle_key_event};
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
+use ratatui::layout::Rect;
+use skillcapped_generator::{
+    app::{App, Mode},
+    clipboard::MemoryClipboard,
+    input::{handle_key_event, handle_mouse_event, handle_paste_event},
+    keymap::{Action, KeySpec, Keymap},
+    status::StatusLevel,
+    version::GameVersion,
+};
+use std::{cell::RefCell, rc::Rc};
+
+/// An app with an in-memory clipboard, so tests never touch the real one.
+fn test_app() -> App {
+    test_app_with_clipboard().0
+}
+
+fn test_app_with_clipboard() -> (App, Rc<RefCell<MemoryClipboard>>) {
+    let clipboard = Rc::new(RefCell::new(MemoryClipboard::default()));
+    let mut app = App::new();
+    app.clipboard_backend = Some(clipboard.clone());
+    (app, clipboard)
+}
 
 fn create_key_event(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
     KeyEvent {
@@ -12,7 +36,7 @@ fn create_key_event(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
 
 #[test]
 fn test_handle_regular_char() {
-    let mut app = App::new();
+    let mut app = test_app();
     let key = create_key_event(KeyCode::Char('A'), KeyModifiers::empty());
 
     let should_quit = handle_key_event(&mut app, key);
@@ -22,7 +46,7 @@ fn test_handle_regular_char() {
 
 #[test]
 fn test_handle_backspace() {
-    let mut app = App::new();
+    let mut app = test_app();
     app.battlenet_id = "Test".to_string();
     let key = create_key_event(KeyCode::Backspace, KeyModifiers::empty());
 
@@ -33,22 +57,24 @@ fn test_handle_backspace() {
 
 #[test]
 fn test_handle_tab_toggle_version() {
-    let mut app = App::new();
-    app.version = "retail".to_string();
+    let mut app = test_app();
+    app.version = GameVersion::Retail;
     let key = create_key_event(KeyCode::Tab, KeyModifiers::empty());
 
     let should_quit = handle_key_event(&mut app, key);
     assert!(!should_quit);
-    assert_eq!(app.version, "classic");
+    assert_eq!(app.version, GameVersion::Classic);
 
-    let should_quit = handle_key_event(&mut app, key);
-    assert!(!should_quit);
-    assert_eq!(app.version, "retail");
+    for _ in 1..GameVersion::ALL.len() {
+        let should_quit = handle_key_event(&mut app, key);
+        assert!(!should_quit);
+    }
+    assert_eq!(app.version, GameVersion::Retail);
 }
 
 #[test]
 fn test_handle_enter_toggle_lowercase() {
-    let mut app = App::new();
+    let mut app = test_app();
     assert!(!app.use_lowercase);
 
     let key = create_key_event(KeyCode::Enter, KeyModifiers::empty());
@@ -63,7 +89,7 @@ fn test_handle_enter_toggle_lowercase() {
 
 #[test]
 fn test_handle_escape_reset() {
-    let mut app = App::new();
+    let mut app = test_app();
     app.battlenet_id = "TestUser#1234".to_string();
     let key = create_key_event(KeyCode::Esc, KeyModifiers::empty());
 
@@ -74,7 +100,7 @@ fn test_handle_escape_reset() {
 
 #[test]
 fn test_handle_ctrl_q_quit() {
-    let mut app = App::new();
+    let mut app = test_app();
     let key = create_key_event(KeyCode::Char('q'), KeyModifiers::CONTROL);
 
     let should_quit = handle_key_event(&mut app, key);
@@ -83,30 +109,36 @@ fn test_handle_ctrl_q_quit() {
 
 #[test]
 fn test_handle_ctrl_c_copy_invalid_id() {
-    let mut app = App::new();
+    let mut app = test_app();
     app.battlenet_id = "invalid".to_string(); // Invalid Battle.net ID
     let key = create_key_event(KeyCode::Char('c'), KeyModifiers::CONTROL);
 
     let should_quit = handle_key_event(&mut app, key);
     assert!(!should_quit);
-    // Should not set copy_feedback for invalid ID
-    assert!(app.copy_feedback.is_none());
+    // The status area explains why nothing was copied
+    let status = app.status.expect("copy failure is reported");
+    assert_eq!(status.level, StatusLevel::Error);
+    assert!(status.text.starts_with("Nothing to copy: "));
 }
 
 #[test]
 fn test_handle_ctrl_c_copy_valid_id() {
-    let mut app = App::new();
+    let (mut app, clipboard) = test_app_with_clipboard();
     app.battlenet_id = "TestUser#1234".to_string(); // Valid Battle.net ID
     let key = create_key_event(KeyCode::Char('c'), KeyModifiers::CONTROL);
 
     let should_quit = handle_key_event(&mut app, key);
     assert!(!should_quit);
-    // Note: copy_feedback might be set if clipboard is available
+    assert_eq!(clipboard.borrow().text, Some(app.generate_code().unwrap()));
+    let status = app.status.expect("the copy is reported");
+    assert_eq!(status.level, StatusLevel::Info);
+    assert_eq!(status.text, "Copied to clipboard via auto");
+    assert_eq!(app.history.len(), 1);
 }
 
 #[test]
 fn test_ignore_char_with_modifiers() {
-    let mut app = App::new();
+    let mut app = test_app();
     let key = create_key_event(KeyCode::Char('a'), KeyModifiers::CONTROL);
 
     let should_quit = handle_key_event(&mut app, key);
@@ -116,7 +148,7 @@ fn test_ignore_char_with_modifiers() {
 
 #[test]
 fn test_ignore_char_with_alt() {
-    let mut app = App::new();
+    let mut app = test_app();
     let key = create_key_event(KeyCode::Char('a'), KeyModifiers::ALT);
 
     let should_quit = handle_key_event(&mut app, key);
@@ -126,38 +158,33 @@ fn test_ignore_char_with_alt() {
 
 #[test]
 fn test_ignore_unknown_key() {
-    let mut app = App::new();
+    let mut app = test_app();
     let key = create_key_event(KeyCode::Home, KeyModifiers::empty());
 
     let should_quit = handle_key_event(&mut app, key);
     assert!(!should_quit);
     // App state should remain unchanged
     assert_eq!(app.battlenet_id, "");
-    assert_eq!(app.version, "retail");
+    assert_eq!(app.version, GameVersion::Retail);
     assert!(!app.use_lowercase);
 }
 
 #[test]
 fn test_handle_ctrl_v_paste() {
-    let mut app = App::new();
+    let (mut app, clipboard) = test_app_with_clipboard();
     app.battlenet_id = "OldData".to_string();
+    clipboard.borrow_mut().text = Some("My tag is Foo#1234".to_string());
     let key = create_key_event(KeyCode::Char('v'), KeyModifiers::CONTROL);
 
     let should_quit = handle_key_event(&mut app, key);
     assert!(!should_quit);
-    // We can't easily test actual clipboard functionality in unit tests,
-    // but we can verify the handler doesn't panic and processes the input
-    // The battlenet_id might change if clipboard is available, or stay the same if not
+    assert_eq!(app.battlenet_id, "Foo#1234");
 }
 
 #[test]
 fn test_handle_ctrl_g_github_integration() {
-    let mut app = App::new();
-    let original_state = (
-        app.battlenet_id.clone(),
-        app.use_lowercase,
-        app.version.clone(),
-    );
+    let mut app = test_app();
+    let original_state = (app.battlenet_id.clone(), app.use_lowercase, app.version);
 
     let key = create_key_event(KeyCode::Char('g'), KeyModifiers::CONTROL);
     let should_quit = handle_key_event(&mut app, key);
@@ -175,21 +202,21 @@ fn test_handle_ctrl_g_github_integration() {
 fn test_has_modifiers_comprehensive() {
     // Test CONTROL modifier
     let key = create_key_event(KeyCode::Char('a'), KeyModifiers::CONTROL);
-    let should_quit = handle_key_event(&mut App::new(), key);
+    let should_quit = handle_key_event(&mut test_app(), key);
     assert!(!should_quit);
 
     // Test ALT modifier
     let key = create_key_event(KeyCode::Char('b'), KeyModifiers::ALT);
-    let should_quit = handle_key_event(&mut App::new(), key);
+    let should_quit = handle_key_event(&mut test_app(), key);
     assert!(!should_quit);
 
     // Test SUPER modifier
     let key = create_key_event(KeyCode::Char('c'), KeyModifiers::SUPER);
-    let should_quit = handle_key_event(&mut App::new(), key);
+    let should_quit = handle_key_event(&mut test_app(), key);
     assert!(!should_quit);
 
     // Test SHIFT modifier (should not block character input)
-    let mut app = App::new();
+    let mut app = test_app();
     let key = create_key_event(KeyCode::Char('d'), KeyModifiers::SHIFT);
     let should_quit = handle_key_event(&mut app, key);
     assert!(!should_quit);
@@ -198,7 +225,7 @@ fn test_has_modifiers_comprehensive() {
 
 #[test]
 fn test_handle_copy_code_invalid_id() {
-    let mut app = App::new();
+    let mut app = test_app();
     app.battlenet_id = "invalid".to_string(); // Invalid Battle.net ID
 
     let key = create_key_event(KeyCode::Char('c'), KeyModifiers::CONTROL);
@@ -206,40 +233,30 @@ fn test_handle_copy_code_invalid_id() {
 
     assert!(!should_quit);
     // Should not crash when trying to copy invalid ID
-    assert!(app.copy_feedback.is_none()); // No feedback for invalid ID
-}
-
-#[test]
-fn test_handle_copy_code_generation_error() {
-    let mut app = App::new();
-    app.battlenet_id = "TestUser#1234".to_string();
-    app.version = "invalid_version".to_string(); // This will cause generate_code to fail
-
-    let key = create_key_event(KeyCode::Char('c'), KeyModifiers::CONTROL);
-    let should_quit = handle_key_event(&mut app, key);
-
-    assert!(!should_quit);
-    // Should not crash when code generation fails
-    assert!(app.copy_feedback.is_none()); // No feedback when generation fails
+    assert!(app
+        .status
+        .is_some_and(|status| status.level == StatusLevel::Error));
 }
 
 #[test]
 fn test_handle_paste_empty_clipboard() {
-    let mut app = App::new();
+    let mut app = test_app();
     app.battlenet_id = "existing".to_string();
 
-    // This test checks the clipboard error handling path
+    // The in-memory clipboard starts out empty, so reading it fails
     let key = create_key_event(KeyCode::Char('v'), KeyModifiers::CONTROL);
     let should_quit = handle_key_event(&mut app, key);
 
     assert!(!should_quit);
-    // The function should handle clipboard errors gracefully
-    // Note: In real scenario, this depends on clipboard state
+    assert_eq!(app.battlenet_id, "existing");
+    let status = app.status.expect("the paste failure is reported");
+    assert_eq!(status.level, StatusLevel::Error);
+    assert!(status.text.starts_with("Paste failed: "));
 }
 
 #[test]
 fn test_handle_paste_with_special_characters() {
-    let mut app = App::new();
+    let mut app = test_app();
 
     // Simulate pasting text with special characters that need sanitization
     // Since we can't control the actual clipboard in tests, we test the sanitization logic
@@ -275,7 +292,7 @@ fn test_has_modifiers_edge_cases() {
 
 #[test]
 fn test_character_input_comprehensive() {
-    let mut app = App::new();
+    let mut app = test_app();
 
     // Test various characters
     let chars = vec!['a', 'Z', '1', '#', '_', '-', ' '];
@@ -291,7 +308,7 @@ fn test_character_input_comprehensive() {
 
 #[test]
 fn test_multiple_key_sequence() {
-    let mut app = App::new();
+    let mut app = test_app();
 
     // Simulate typing a Battle.net ID
     let sequence = "TestUser#1234";
@@ -315,10 +332,10 @@ fn test_multiple_key_sequence() {
 
 #[test]
 fn test_reset_and_toggle_combinations() {
-    let mut app = App::new();
+    let mut app = test_app();
     app.battlenet_id = "Test#1234".to_string();
     app.use_lowercase = false;
-    app.version = "retail".to_string();
+    app.version = GameVersion::Retail;
 
     // Test escape reset
     let key = create_key_event(KeyCode::Esc, KeyModifiers::empty());
@@ -341,43 +358,358 @@ fn test_reset_and_toggle_combinations() {
     let key = create_key_event(KeyCode::Tab, KeyModifiers::empty());
     let should_quit = handle_key_event(&mut app, key);
     assert!(!should_quit);
-    assert_eq!(app.version, "classic");
+    assert_eq!(app.version, GameVersion::Classic);
 }
 
 #[test]
-fn test_handle_ctrl_c_with_invalid_version() {
-    let mut app = App::new();
-    app.battlenet_id = "TestUser#1234".to_string();
-    app.version = "invalid_version".to_string(); // This will cause generate_code to fail
+fn test_paste_battlenet_id_error_handling() {
+    // This test mainly verifies the function can be called without panicking
+    // The actual clipboard error paths are hard to test reliably in a unit test
+    let mut app = test_app();
+    let key = create_key_event(KeyCode::Char('v'), KeyModifiers::CONTROL);
 
-    let key = create_key_event(KeyCode::Char('c'), KeyModifiers::CONTROL);
     let should_quit = handle_key_event(&mut app, key);
     assert!(!should_quit);
-    // The copy_feedback should not be set if generation fails
-    assert!(app.copy_feedback.is_none());
+    // The function should complete without crashing regardless of clipboard state
 }
 
 #[test]
-fn test_generate_code_with_invalid_version() {
-    let mut app = App::new();
+fn test_handle_ctrl_d_toggles_verify_mode() {
+    let mut app = test_app();
+    let key = create_key_event(KeyCode::Char('d'), KeyModifiers::CONTROL);
+
+    assert!(!handle_key_event(&mut app, key));
+    assert_eq!(app.mode, Mode::Verify);
+
+    assert!(!handle_key_event(&mut app, key));
+    assert_eq!(app.mode, Mode::Generate);
+}
+
+#[test]
+fn test_verify_mode_edits_verify_input() {
+    let mut app = test_app();
     app.battlenet_id = "TestUser#1234".to_string();
-    app.version = "invalid_version".to_string();
+    app.mode = Mode::Verify;
 
-    let result = app.generate_code();
-    assert!(result.is_err());
-    assert_eq!(result.unwrap_err(), "Invalid version");
+    for c in "abc=".chars() {
+        handle_key_event(
+            &mut app,
+            create_key_event(KeyCode::Char(c), KeyModifiers::empty()),
+        );
+    }
+    assert_eq!(app.verify_input, "abc=");
+
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Backspace, KeyModifiers::empty()),
+    );
+    assert_eq!(app.verify_input, "abc");
+
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Esc, KeyModifiers::empty()),
+    );
+    assert_eq!(app.verify_input, "");
+
+    // The Battle.net ID is left untouched while verifying
+    assert_eq!(app.battlenet_id, "TestUser#1234");
 }
 
-// Test to ensure clipboard errors are handled gracefully
-// Note: These tests may not hit the exact error paths due to clipboard system dependencies
 #[test]
-fn test_paste_battlenet_id_error_handling() {
-    // This test mainly verifies the function can be called without panicking
-    // The actual clipboard error paths are hard to test reliably in a unit test
-    let mut app = App::new();
-    let key = create_key_event(KeyCode::Char('v'), KeyModifiers::CONTROL);
+fn test_handle_backtab_cycles_addon() {
+    let mut app = test_app();
+    let key = create_key_event(KeyCode::BackTab, KeyModifiers::SHIFT);
 
-    let should_quit = handle_key_event(&mut app, key);
-    assert!(!should_quit);
-    // The function should complete without crashing regardless of clipboard state
+    handle_key_event(&mut app, key);
+    assert_eq!(app.addon.as_deref(), Some("WA4"));
+
+    handle_key_event(&mut app, key);
+    assert_eq!(app.addon.as_deref(), Some("WA5"));
+}
+
+#[test]
+fn test_profile_panel_keys() {
+    let mut app = test_app();
+    app.battlenet_id = "Xerrion#2624".to_string();
+
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Char('p'), KeyModifiers::CONTROL),
+    );
+    assert_eq!(app.mode, Mode::Profiles);
+
+    // 'a' starts adding, typed characters edit the label instead of the ID.
+    for c in ['a', '!'] {
+        handle_key_event(
+            &mut app,
+            create_key_event(KeyCode::Char(c), KeyModifiers::empty()),
+        );
+    }
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Enter, KeyModifiers::empty()),
+    );
+    assert_eq!(app.profiles.get(0).unwrap().label, "Xerrion!");
+    assert_eq!(app.battlenet_id, "Xerrion#2624");
+
+    app.battlenet_id.clear();
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Enter, KeyModifiers::empty()),
+    );
+    assert_eq!(app.mode, Mode::Generate);
+    assert_eq!(app.battlenet_id, "Xerrion#2624");
+
+    app.toggle_profiles();
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Char('d'), KeyModifiers::empty()),
+    );
+    assert!(app.profiles.is_empty());
+
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Esc, KeyModifiers::empty()),
+    );
+    assert_eq!(app.mode, Mode::Generate);
+}
+
+#[test]
+fn test_ctrl_r_toggles_history() {
+    let mut app = test_app();
+    let key = create_key_event(KeyCode::Char('r'), KeyModifiers::CONTROL);
+
+    handle_key_event(&mut app, key);
+    assert_eq!(app.mode, Mode::History);
+
+    // Typing does not edit the Battle.net ID in the history panel.
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Char('x'), KeyModifiers::empty()),
+    );
+    assert!(app.battlenet_id.is_empty());
+
+    // Enter with an empty history says why nothing was copied.
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Enter, KeyModifiers::empty()),
+    );
+    assert!(app
+        .status
+        .as_ref()
+        .is_some_and(|status| status.text.contains("no history entry selected")));
+
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Esc, KeyModifiers::empty()),
+    );
+    assert_eq!(app.mode, Mode::Generate);
+}
+
+#[test]
+fn test_line_editing_keys() {
+    let mut app = test_app();
+    app.battlenet_id = "TestUser#1234".to_string();
+    let press = |app: &mut App, code: KeyCode, modifiers: KeyModifiers| {
+        handle_key_event(app, create_key_event(code, modifiers));
+    };
+
+    press(&mut app, KeyCode::Home, KeyModifiers::empty());
+    press(&mut app, KeyCode::Delete, KeyModifiers::empty());
+    assert_eq!(app.battlenet_id, "estUser#1234");
+
+    press(&mut app, KeyCode::End, KeyModifiers::empty());
+    press(&mut app, KeyCode::Left, KeyModifiers::empty());
+    press(&mut app, KeyCode::Backspace, KeyModifiers::empty());
+    assert_eq!(app.battlenet_id, "estUser#124");
+
+    press(&mut app, KeyCode::Right, KeyModifiers::empty());
+    press(&mut app, KeyCode::Char('w'), KeyModifiers::CONTROL);
+    assert_eq!(app.battlenet_id, "estUser#");
+
+    press(&mut app, KeyCode::Char('u'), KeyModifiers::CONTROL);
+    assert_eq!(app.battlenet_id, "");
+}
+
+#[test]
+fn test_ctrl_w_ignored_outside_generate_mode() {
+    let mut app = test_app();
+    app.battlenet_id = "TestUser#1234".to_string();
+    app.mode = Mode::Verify;
+
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Char('w'), KeyModifiers::CONTROL),
+    );
+
+    assert_eq!(app.battlenet_id, "TestUser#1234");
+}
+
+#[test]
+fn test_ctrl_z_and_ctrl_y() {
+    let mut app = test_app();
+    app.battlenet_id = "TestUser#1234".to_string();
+
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Esc, KeyModifiers::empty()),
+    );
+    assert_eq!(app.battlenet_id, "");
+
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Char('z'), KeyModifiers::CONTROL),
+    );
+    assert_eq!(app.battlenet_id, "TestUser#1234");
+
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Char('y'), KeyModifiers::CONTROL),
+    );
+    assert_eq!(app.battlenet_id, "");
+}
+
+#[test]
+fn test_rebound_keys() {
+    let mut app = test_app();
+    app.keymap = Keymap::with_overrides(
+        &[
+            (Action::ToggleCase, KeySpec::One("f3".to_string())),
+            (Action::Quit, KeySpec::One("ctrl+x".to_string())),
+        ]
+        .into_iter()
+        .collect(),
+    )
+    .unwrap();
+
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Enter, KeyModifiers::NONE),
+    );
+    assert!(!app.use_lowercase);
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::F(3), KeyModifiers::NONE),
+    );
+    assert!(app.use_lowercase);
+
+    assert!(!handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Char('q'), KeyModifiers::CONTROL)
+    ));
+    assert!(handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Char('x'), KeyModifiers::CONTROL)
+    ));
+}
+
+#[test]
+fn test_generate_only_keys_are_free_in_panels() {
+    let mut app = test_app();
+    app.mode = Mode::History;
+
+    // Enter re-copies in the history panel instead of toggling lowercase.
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Enter, KeyModifiers::NONE),
+    );
+    assert!(!app.use_lowercase);
+
+    handle_key_event(&mut app, create_key_event(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(app.mode, Mode::Generate);
+}
+
+#[test]
+fn test_paste_selection_keys() {
+    let mut app = test_app();
+    app.paste_battlenet_id("Foo#1234, Bar#5678");
+    assert_eq!(app.mode, Mode::PasteSelection);
+
+    // Enter picks instead of toggling lowercase.
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Down, KeyModifiers::NONE),
+    );
+    handle_key_event(
+        &mut app,
+        create_key_event(KeyCode::Enter, KeyModifiers::NONE),
+    );
+    assert_eq!(app.battlenet_id, "Bar#5678");
+    assert!(!app.use_lowercase);
+    assert_eq!(app.mode, Mode::Generate);
+
+    app.paste_battlenet_id("Foo#1234, Bar#5678");
+    handle_key_event(&mut app, create_key_event(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(app.battlenet_id, "Bar#5678");
+    assert_eq!(app.mode, Mode::Generate);
+}
+
+fn click(app: &mut App, kind: MouseEventKind, column: u16, row: u16) {
+    let mouse = MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    };
+    handle_mouse_event(app, mouse, Rect::new(0, 0, 80, 24));
+}
+
+#[test]
+fn test_mouse_click_selects_version() {
+    let mut app = test_app();
+
+    // The version bar starts inside its border at column 3, row 6.
+    click(&mut app, MouseEventKind::Down(MouseButton::Left), 15, 6);
+    assert_eq!(app.version, GameVersion::Classic);
+
+    click(&mut app, MouseEventKind::Down(MouseButton::Right), 3, 6);
+    click(&mut app, MouseEventKind::Moved, 3, 6);
+    assert_eq!(app.version, GameVersion::Classic);
+}
+
+#[test]
+fn test_mouse_click_moves_input_cursor() {
+    let mut app = test_app();
+    app.battlenet_id = "TestUser#1234".to_string();
+
+    // "✅ Battle.net ID: " takes 18 cells after the border at column 3.
+    click(
+        &mut app,
+        MouseEventKind::Down(MouseButton::Left),
+        3 + 18 + 4,
+        2,
+    );
+    assert_eq!(app.cursor_position(), 4);
+
+    click(&mut app, MouseEventKind::Down(MouseButton::Left), 3, 2);
+    assert_eq!(app.cursor_position(), 0);
+}
+
+#[test]
+fn test_paste_event_is_one_undoable_edit() {
+    let mut app = test_app();
+    app.battlenet_id = "Old#1234".to_string();
+
+    handle_paste_event(&mut app, "Hi, my tag is Foo#1234!");
+    assert_eq!(app.battlenet_id, "Foo#1234");
+
+    app.undo();
+    assert_eq!(app.battlenet_id, "Old#1234");
+}
+
+#[test]
+fn test_paste_event_by_mode() {
+    let mut app = test_app();
+
+    handle_paste_event(&mut app, "Foo#1234 Bar#5678");
+    assert_eq!(app.mode, Mode::PasteSelection);
+
+    app.mode = Mode::Verify;
+    handle_paste_event(&mut app, "  c29tZWNvZGU=\n");
+    assert_eq!(app.verify_input, "c29tZWNvZGU=");
+
+    app.mode = Mode::History;
+    handle_paste_event(&mut app, "Baz#1234");
+    assert_eq!(app.battlenet_id, "");
+    assert_eq!(app.mode, Mode::History);
 }
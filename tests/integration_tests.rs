@@ -1,7 +1,7 @@
 use base64::{engine::general_purpose, Engine as _};
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
 use ratatui::{backend::TestBackend, Terminal};
-use skillcapped_generator::{app::App, input::handle_key_event, ui::draw_ui};
+use skillcapped_generator::{app::App, input::handle_key_event, ui::draw_ui, version::GameVersion};
 
 fn create_key_event(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
     KeyEvent {
@@ -46,13 +46,13 @@ fn test_version_switching_workflow() {
     app.battlenet_id = "TestUser#1234".to_string();
 
     // Start with retail
-    assert_eq!(app.version, "retail");
+    assert_eq!(app.version, GameVersion::Retail);
     let retail_code = app.generate_code().unwrap();
 
     // Switch to classic
     let tab_key = create_key_event(KeyCode::Tab, KeyModifiers::empty());
     handle_key_event(&mut app, tab_key);
-    assert_eq!(app.version, "classic");
+    assert_eq!(app.version, GameVersion::Classic);
     let classic_code = app.generate_code().unwrap();
 
     // Both should generate valid codes
@@ -135,7 +135,7 @@ fn test_reset_workflow() {
     // Set up some state
     app.battlenet_id = "TestUser#1234".to_string();
     app.use_lowercase = true;
-    app.version = "classic".to_string();
+    app.version = GameVersion::Classic;
 
     // Reset input
     let esc_key = create_key_event(KeyCode::Esc, KeyModifiers::empty());
@@ -144,7 +144,7 @@ fn test_reset_workflow() {
     // Only battlenet_id should be reset
     assert_eq!(app.battlenet_id, "");
     assert!(app.use_lowercase); // Should remain true
-    assert_eq!(app.version, "classic"); // Should remain classic
+    assert_eq!(app.version, GameVersion::Classic); // Should remain classic
 }
 
 #[test]
@@ -179,7 +179,7 @@ fn test_ui_with_different_states() {
 
     // Test with classic version
     let mut app = App::new();
-    app.version = "classic".to_string();
+    app.version = GameVersion::Classic;
     app.battlenet_id = "TestUser#1234".to_string();
     assert!(terminal.draw(|f| draw_ui(f, &app)).is_ok());
 }
@@ -0,0 +1,147 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use skillcapped_generator::{
+    error::Error,
+    keymap::{Action, KeyBinding, KeySpec, Keymap},
+};
+use std::collections::BTreeMap;
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+    KeyEvent::new(code, modifiers)
+}
+
+fn overrides(entries: &[(Action, KeySpec)]) -> BTreeMap<Action, KeySpec> {
+    entries.iter().cloned().collect()
+}
+
+#[test]
+fn test_default_keymap() {
+    let keymap = Keymap::default();
+
+    assert_eq!(
+        keymap.action_for(&key(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+        Some(Action::Copy)
+    );
+    assert_eq!(
+        keymap.action_for(&key(KeyCode::Enter, KeyModifiers::NONE)),
+        Some(Action::ToggleCase)
+    );
+    assert_eq!(
+        keymap.action_for(&key(KeyCode::BackTab, KeyModifiers::SHIFT)),
+        Some(Action::CycleAddon)
+    );
+    assert_eq!(
+        keymap.action_for(&key(KeyCode::Char('c'), KeyModifiers::NONE)),
+        None
+    );
+    assert_eq!(keymap.label(Action::Copy), "Ctrl+C");
+    assert_eq!(keymap.label(Action::CycleAddon), "Shift+Tab");
+}
+
+#[test]
+fn test_parse_key_binding() {
+    let parse = |s: &str| s.parse::<KeyBinding>().unwrap();
+
+    assert_eq!(
+        parse("ctrl+c"),
+        KeyBinding::new(KeyCode::Char('c'), KeyModifiers::CONTROL)
+    );
+    assert_eq!(
+        parse(" Alt+L "),
+        KeyBinding::new(KeyCode::Char('l'), KeyModifiers::ALT)
+    );
+    assert_eq!(
+        parse("f5"),
+        KeyBinding::new(KeyCode::F(5), KeyModifiers::NONE)
+    );
+    assert_eq!(
+        parse("esc"),
+        KeyBinding::new(KeyCode::Esc, KeyModifiers::NONE)
+    );
+    assert_eq!(
+        parse("shift+tab"),
+        KeyBinding::new(KeyCode::BackTab, KeyModifiers::SHIFT)
+    );
+}
+
+#[test]
+fn test_parse_invalid_key_binding() {
+    for invalid in ["", "ctrl+", "hyper+c", "ctrl+cc", "f99"] {
+        assert_eq!(
+            invalid.parse::<KeyBinding>(),
+            Err(Error::InvalidKeyBinding(invalid.to_string())),
+            "{invalid:?}"
+        );
+    }
+}
+
+#[test]
+fn test_key_binding_display() {
+    for (text, label) in [
+        ("ctrl+y", "Ctrl+Y"),
+        ("alt+ctrl+k", "Ctrl+Alt+K"),
+        ("f2", "F2"),
+        ("enter", "Enter"),
+        ("space", "Space"),
+        ("x", "x"),
+    ] {
+        assert_eq!(text.parse::<KeyBinding>().unwrap().to_string(), label);
+    }
+}
+
+#[test]
+fn test_key_binding_matches_ignoring_letter_case() {
+    let binding: KeyBinding = "ctrl+k".parse().unwrap();
+
+    assert!(binding.matches(&key(KeyCode::Char('K'), KeyModifiers::CONTROL)));
+    assert!(binding.matches(&key(
+        KeyCode::Char('K'),
+        KeyModifiers::CONTROL | KeyModifiers::SHIFT
+    )));
+    assert!(!binding.matches(&key(KeyCode::Char('k'), KeyModifiers::NONE)));
+    assert!(!binding.matches(&key(
+        KeyCode::Char('k'),
+        KeyModifiers::CONTROL | KeyModifiers::ALT
+    )));
+}
+
+#[test]
+fn test_overrides_replace_default_keys() {
+    let keymap = Keymap::with_overrides(&overrides(&[
+        (Action::Copy, KeySpec::One("ctrl+y".to_string())),
+        (
+            Action::ToggleCase,
+            KeySpec::Many(vec!["f3".to_string(), "alt+l".to_string()]),
+        ),
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        keymap.action_for(&key(KeyCode::Char('y'), KeyModifiers::CONTROL)),
+        Some(Action::Copy)
+    );
+    assert_eq!(
+        keymap.action_for(&key(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+        None
+    );
+    assert_eq!(
+        keymap.action_for(&key(KeyCode::Enter, KeyModifiers::NONE)),
+        None
+    );
+    assert_eq!(
+        keymap.action_for(&key(KeyCode::Char('l'), KeyModifiers::ALT)),
+        Some(Action::ToggleCase)
+    );
+    assert_eq!(keymap.label(Action::ToggleCase), "F3");
+    // Ctrl+Y belonged to redo, which is now unbound.
+    assert_eq!(keymap.label(Action::Redo), "unbound");
+}
+
+#[test]
+fn test_overrides_reject_invalid_keys() {
+    let result = Keymap::with_overrides(&overrides(&[(
+        Action::Quit,
+        KeySpec::One("ctrl+shift".to_string()),
+    )]));
+
+    assert!(matches!(result, Err(Error::InvalidKeyBinding(_))));
+}
@@ -0,0 +1,136 @@
+use skillcapped_generator::app::App;
+use skillcapped_generator::output::{write_record, write_records, CodeRecord, OutputFormat};
+use skillcapped_generator::version::GameVersion;
+
+fn generator(version: GameVersion, use_lowercase: bool) -> App {
+    let mut app = App::new();
+    app.version = version;
+    app.use_lowercase = use_lowercase;
+    app
+}
+
+fn render(format: OutputFormat, records: &[CodeRecord]) -> String {
+    let mut out = Vec::new();
+    write_records(format, records, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn test_code_record_valid() {
+    let record = CodeRecord::generate(&generator(GameVersion::Classic, true), "TestUser#1234");
+
+    assert_eq!(record.input, "TestUser#1234");
+    assert_eq!(record.normalized_id, "testuser#1234");
+    assert_eq!(record.version, "classic");
+    assert!(record.lowercase);
+    assert_eq!(
+        record.code.as_deref(),
+        Some("dGVzdHVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl")
+    );
+    assert!(record.errors.is_empty());
+    assert!(record.is_valid());
+}
+
+#[test]
+fn test_code_record_invalid() {
+    let record = CodeRecord::generate(&generator(GameVersion::Retail, false), "invalid");
+
+    assert!(record.code.is_none());
+    assert_eq!(record.errors.len(), 1);
+    assert!(!record.is_valid());
+}
+
+#[test]
+fn test_write_jsonl() {
+    let records = [
+        CodeRecord::generate(&generator(GameVersion::Retail, false), "TestUser#1234"),
+        CodeRecord::generate(&generator(GameVersion::Retail, false), "invalid"),
+    ];
+    let output = render(OutputFormat::Jsonl, &records);
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines.len(), 2);
+    let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(first["normalized_id"], "TestUser#1234");
+    assert_eq!(first["code"], "VGVzdFVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl");
+    assert_eq!(first["errors"], serde_json::json!([]));
+
+    let second: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+    assert!(second["code"].is_null());
+    assert_eq!(second["errors"].as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_write_json_array_and_object() {
+    let records = [CodeRecord::generate(
+        &generator(GameVersion::Retail, false),
+        "TestUser#1234",
+    )];
+
+    let array: serde_json::Value =
+        serde_json::from_str(&render(OutputFormat::Json, &records)).unwrap();
+    assert_eq!(array.as_array().unwrap().len(), 1);
+
+    let mut out = Vec::new();
+    write_record(OutputFormat::Json, &records[0], &mut out).unwrap();
+    let object: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    assert_eq!(object["version"], "retail");
+    assert_eq!(object["lowercase"], false);
+}
+
+#[test]
+fn test_write_csv_escapes_fields() {
+    let records = [
+        CodeRecord::generate(&generator(GameVersion::Retail, false), "TestUser#1234"),
+        CodeRecord::generate(&generator(GameVersion::Retail, false), "Bad,\"Name\""),
+    ];
+    let output = render(OutputFormat::Csv, &records);
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(
+        lines[0],
+        "input,normalized_id,version,lowercase,code,errors"
+    );
+    assert_eq!(
+        lines[1],
+        "TestUser#1234,TestUser#1234,retail,false,VGVzdFVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl,"
+    );
+    assert!(lines[2].starts_with("\"Bad,\"\"Name\"\"\",\"Bad,\"\"Name\"\"\",retail,false,,"));
+}
+
+#[test]
+fn test_write_plain_skips_invalid() {
+    let records = [
+        CodeRecord::generate(&generator(GameVersion::Retail, false), "TestUser#1234"),
+        CodeRecord::generate(&generator(GameVersion::Retail, false), "invalid"),
+    ];
+
+    assert_eq!(
+        render(OutputFormat::Plain, &records),
+        "TestUser#1234\tVGVzdFVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl\n"
+    );
+}
+
+#[test]
+fn test_write_decoded_formats() {
+    use skillcapped_generator::{
+        app::App,
+        output::{write_decoded, DecodeRecord},
+    };
+
+    let decoded = App::new()
+        .decode_code("VGVzdFVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl")
+        .unwrap();
+    let record = DecodeRecord::new(" VGVzdFVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl ", &decoded);
+
+    let mut out = Vec::new();
+    write_decoded(OutputFormat::Plain, &record, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "TestUser#1234\tWA5\n");
+
+    let mut out = Vec::new();
+    write_decoded(OutputFormat::Csv, &record, &mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "code,battlenet_id,config,lowercase\nVGVzdFVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl,TestUser#1234,WA5,false\n"
+    );
+}
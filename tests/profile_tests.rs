@@ -0,0 +1,220 @@
+use skillcapped_generator::{
+    app::{App, Mode},
+    error::Error,
+    profile::{Profile, ProfileEditKind, ProfileStore, PROFILES_FILE_NAME},
+    status::StatusLevel,
+    version::GameVersion,
+};
+use std::fs;
+
+fn profile(label: &str, battle_tag: &str) -> Profile {
+    Profile {
+        label: label.to_string(),
+        battle_tag: battle_tag.to_string(),
+        version: GameVersion::Retail,
+        lowercase: false,
+    }
+}
+
+fn app_with_profiles() -> App {
+    let mut app = App::new();
+    app.profiles.add(profile("Main", "Xerrion#2624")).unwrap();
+    app.profiles
+        .add(Profile {
+            version: GameVersion::Classic,
+            lowercase: true,
+            ..profile("Alt", "TestUser#1234")
+        })
+        .unwrap();
+    app
+}
+
+#[test]
+fn test_profile_store_add_rename_remove() {
+    let mut store = ProfileStore::default();
+    store.add(profile(" Main ", "Xerrion#2624")).unwrap();
+    store.add(profile("Alt", "TestUser#1234")).unwrap();
+
+    assert_eq!(store.len(), 2);
+    assert_eq!(store.get(0).unwrap().label, "Main");
+
+    assert_eq!(
+        store.add(profile("main", "Other#1234")),
+        Err(Error::DuplicateProfile("main".to_string()))
+    );
+    assert!(matches!(
+        store.add(profile("  ", "Other#1234")),
+        Err(Error::InvalidProfiles(_))
+    ));
+
+    store.rename(1, "Smurf").unwrap();
+    assert_eq!(store.get(1).unwrap().label, "Smurf");
+    // Renaming a profile to its own label with different case is allowed.
+    store.rename(1, "SMURF").unwrap();
+    assert_eq!(
+        store.rename(1, "Main"),
+        Err(Error::DuplicateProfile("Main".to_string()))
+    );
+
+    assert_eq!(store.remove(0).unwrap().label, "Main");
+    assert!(store.remove(5).is_none());
+    assert_eq!(store.len(), 1);
+}
+
+#[test]
+fn test_profile_store_toml_round_trip() {
+    let mut store = ProfileStore::default();
+    store.add(profile("Main", "Xerrion#2624")).unwrap();
+    store
+        .add(Profile {
+            version: GameVersion::MopClassic,
+            lowercase: true,
+            ..profile("Alt", "TestUser#1234")
+        })
+        .unwrap();
+
+    let text = store.to_toml();
+    assert!(text.contains("[[profile]]"));
+    assert!(text.contains("version = \"mop-classic\""));
+    assert_eq!(ProfileStore::from_toml(&text).unwrap(), store);
+}
+
+#[test]
+fn test_profile_store_skips_duplicate_labels_in_file() {
+    let text = "[[profile]]\nlabel = \"Main\"\nbattle_tag = \"Alpha#1234\"\n\
+                [[profile]]\nlabel = \"MAIN\"\nbattle_tag = \"Bravo#1234\"";
+
+    let store = ProfileStore::from_toml(text).unwrap();
+    assert_eq!(store.len(), 1);
+    assert_eq!(store.skipped().len(), 1);
+    assert_eq!(
+        store.skipped()[0].error,
+        Error::DuplicateProfile("MAIN".to_string())
+    );
+    // Skipped profiles are written back, so saving does not lose them.
+    assert_eq!(ProfileStore::from_toml(&store.to_toml()).unwrap(), store);
+}
+
+#[test]
+fn test_profile_store_skips_invalid_battle_tags() {
+    let text = "[[profile]]\nlabel = \"Main\"\nbattle_tag = \"Xerrion#2624\"\n\
+                [[profile]]\nlabel = \"Alt\"\nbattle_tag = \"Alt1234\"";
+
+    let store = ProfileStore::from_toml(text).unwrap();
+    assert_eq!(store.len(), 1);
+    assert_eq!(store.get(0).unwrap().label, "Main");
+    let Error::InvalidProfiles(reason) = &store.skipped()[0].error else {
+        panic!("the profile was skipped for another reason");
+    };
+    assert!(reason.starts_with("profile 'Alt' has an invalid BattleTag 'Alt1234': "));
+
+    let mut app = App::new();
+    app.set_profiles(store);
+    let status = app.status.unwrap();
+    assert_eq!(status.level, StatusLevel::Error);
+    assert!(status
+        .text
+        .starts_with("Skipped profiles in profiles.toml: invalid profiles: profile 'Alt'"));
+
+    let mut store = ProfileStore::default();
+    assert!(matches!(
+        store.add(profile("Main", "1Name#1234")),
+        Err(Error::InvalidProfiles(_))
+    ));
+    assert!(store.is_empty());
+}
+
+#[test]
+fn test_use_selected_profile() {
+    let mut app = app_with_profiles();
+    app.toggle_profiles();
+    assert_eq!(app.mode, Mode::Profiles);
+
+    app.select_next_profile();
+    app.select_next_profile();
+    assert_eq!(app.profile_panel.selected, 1);
+    app.use_selected_profile();
+
+    assert_eq!(app.mode, Mode::Generate);
+    assert_eq!(app.battlenet_id, "TestUser#1234");
+    assert_eq!(app.version, GameVersion::Classic);
+    assert!(app.use_lowercase);
+}
+
+#[test]
+fn test_selected_profile_code() {
+    let mut app = app_with_profiles();
+    app.profile_panel.selected = 1;
+
+    assert_eq!(
+        app.selected_profile_code().unwrap(),
+        "dGVzdHVzZXIjMTIzNHZyaWR0Y2V0dnJkaWNl"
+    );
+    // The generator's own input is left alone.
+    assert!(app.battlenet_id.is_empty());
+}
+
+#[test]
+fn test_add_profile_from_current_id() {
+    let mut app = App::new();
+
+    app.start_add_profile();
+    assert!(app.profile_panel.edit.is_none());
+    assert!(app.profile_panel.message.is_some());
+
+    app.battlenet_id = "Xerrion#2624".to_string();
+    app.version = GameVersion::ClassicEra;
+    app.start_add_profile();
+    let edit = app.profile_panel.edit.as_mut().unwrap();
+    assert_eq!(edit.kind, ProfileEditKind::Add);
+    assert_eq!(edit.label, "Xerrion");
+    edit.label = "Main".to_string();
+    app.confirm_profile_edit();
+
+    assert!(app.profile_panel.edit.is_none());
+    let saved = app.selected_profile().unwrap();
+    assert_eq!(saved.label, "Main");
+    assert_eq!(saved.battle_tag, "Xerrion#2624");
+    assert_eq!(saved.version, GameVersion::ClassicEra);
+}
+
+#[test]
+fn test_rename_duplicate_keeps_editing() {
+    let mut app = app_with_profiles();
+    app.profile_panel.selected = 1;
+
+    app.start_rename_profile();
+    app.profile_panel.edit.as_mut().unwrap().label = "Main".to_string();
+    app.confirm_profile_edit();
+
+    assert!(app.profile_panel.edit.is_some());
+    assert_eq!(
+        app.profile_panel.message.as_deref(),
+        Some("a profile named 'Main' already exists")
+    );
+    assert_eq!(app.profiles.get(1).unwrap().label, "Alt");
+}
+
+#[test]
+fn test_delete_selected_profile_saves_file() {
+    let dir = std::env::temp_dir().join(format!(
+        "skillcapped-generator-profiles-{}",
+        std::process::id()
+    ));
+    let path = dir.join(PROFILES_FILE_NAME);
+    let mut app = app_with_profiles();
+    app.profiles_file = Some(path.clone());
+    app.profile_panel.selected = 1;
+
+    app.delete_selected_profile();
+
+    assert_eq!(app.profile_panel.selected, 0);
+    assert_eq!(
+        app.profile_panel.message.as_deref(),
+        Some("Deleted profile 'Alt'")
+    );
+    let saved = ProfileStore::load_from(&path).unwrap();
+    assert_eq!(saved.len(), 1);
+    assert_eq!(saved.get(0).unwrap().label, "Main");
+    let _ = fs::remove_dir_all(dir);
+}
@@ -0,0 +1,223 @@
+use skillcapped_generator::{
+    app::App,
+    error::Error,
+    keymap::{Action, KeySpec},
+    settings::{Settings, SETTINGS_FILE_NAME},
+    status::StatusLevel,
+    theme::ThemeName,
+    version::GameVersion,
+};
+use std::{fs, path::PathBuf};
+
+fn temp_settings_path(test_name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "skillcapped-generator-{test_name}-{}",
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    dir.join("nested").join(SETTINGS_FILE_NAME)
+}
+
+#[test]
+fn test_settings_defaults() {
+    let settings = Settings::from_toml("").unwrap();
+
+    assert_eq!(settings, Settings::default());
+    assert_eq!(settings.version, GameVersion::Retail);
+    assert!(!settings.lowercase);
+    assert!(settings.addon.is_none());
+    assert!(settings.last_id.is_empty());
+}
+
+#[test]
+fn test_settings_toml_round_trip() {
+    let settings = Settings {
+        version: GameVersion::CataclysmClassic,
+        lowercase: true,
+        addon: Some("WA4".to_string()),
+        last_id: "Xerrion#2624".to_string(),
+        save_history: true,
+        ..Settings::default()
+    };
+
+    let text = settings.to_toml();
+    assert!(text.contains("version = \"cata-classic\""));
+    assert_eq!(Settings::from_toml(&text).unwrap(), settings);
+}
+
+#[test]
+fn test_settings_partial_file() {
+    let settings = Settings::from_toml("version = \"classic\"").unwrap();
+
+    assert_eq!(settings.version, GameVersion::Classic);
+    assert!(!settings.lowercase);
+}
+
+#[test]
+fn test_settings_invalid_file() {
+    let result = Settings::from_toml("version = \"tbc\"");
+
+    assert!(matches!(result, Err(Error::InvalidSettings(_))));
+}
+
+#[test]
+fn test_settings_save_and_load() {
+    let path = temp_settings_path("save-and-load");
+    let settings = Settings {
+        version: GameVersion::MopClassic,
+        last_id: "TestUser#1234".to_string(),
+        ..Settings::default()
+    };
+
+    settings.save_to(&path).unwrap();
+
+    assert_eq!(Settings::load_from(&path).unwrap(), settings);
+    let _ = fs::remove_dir_all(path.parent().unwrap().parent().unwrap());
+}
+
+#[test]
+fn test_app_apply_settings() {
+    let mut app = App::new();
+    let settings = Settings {
+        version: GameVersion::ClassicEra,
+        lowercase: true,
+        addon: Some("WA4".to_string()),
+        last_id: "TestUser#1234".to_string(),
+        save_history: true,
+        ..Settings::default()
+    };
+
+    app.apply_settings(&settings);
+
+    assert_eq!(app.version, GameVersion::ClassicEra);
+    assert!(app.use_lowercase);
+    assert_eq!(app.addon.as_deref(), Some("WA4"));
+    assert_eq!(app.battlenet_id, "TestUser#1234");
+    assert_eq!(app.settings(), settings);
+}
+
+#[test]
+fn test_app_apply_settings_skips_unknown_addon() {
+    let mut app = App::new();
+
+    app.apply_settings(&Settings {
+        addon: Some("WA9".to_string()),
+        ..Settings::default()
+    });
+
+    assert!(app.addon.is_none());
+}
+
+#[test]
+fn test_app_save_settings_only_when_changed() {
+    let path = temp_settings_path("save-on-change");
+    let mut app = App::new();
+
+    // Without a settings file nothing is written.
+    app.battlenet_id = "TestUser#1234".to_string();
+    app.save_settings().unwrap();
+    assert!(!path.exists());
+
+    app.settings_file = Some(path.clone());
+    app.save_settings().unwrap();
+    assert_eq!(Settings::load_from(&path).unwrap().last_id, "TestUser#1234");
+
+    // Unchanged settings are not written again.
+    fs::remove_file(&path).unwrap();
+    app.save_settings().unwrap();
+    assert!(!path.exists());
+
+    app.toggle_version();
+    app.save_settings().unwrap();
+    assert_eq!(
+        Settings::load_from(&path).unwrap().version,
+        GameVersion::Classic
+    );
+    let _ = fs::remove_dir_all(path.parent().unwrap().parent().unwrap());
+}
+
+#[test]
+fn test_settings_key_bindings() {
+    let settings =
+        Settings::from_toml("[keys]\ncopy = \"ctrl+y\"\ntoggle-case = [\"f3\", \"alt+l\"]\n")
+            .unwrap();
+
+    assert_eq!(
+        settings.keys.get(&Action::Copy),
+        Some(&KeySpec::One("ctrl+y".to_string()))
+    );
+    assert_eq!(
+        settings.keys.get(&Action::ToggleCase),
+        Some(&KeySpec::Many(vec!["f3".to_string(), "alt+l".to_string()]))
+    );
+    assert_eq!(Settings::from_toml(&settings.to_toml()).unwrap(), settings);
+    assert!(matches!(
+        Settings::from_toml("[keys]\nfly = \"f1\""),
+        Err(Error::InvalidSettings(_))
+    ));
+}
+
+#[test]
+fn test_settings_theme() {
+    assert!(Settings::default().theme.is_none());
+    assert!(!Settings::default().to_toml().contains("theme"));
+
+    let settings = Settings::from_toml("theme = \"light\"").unwrap();
+    assert_eq!(settings.theme, Some(ThemeName::Light));
+    assert_eq!(Settings::from_toml(&settings.to_toml()).unwrap(), settings);
+    assert!(matches!(
+        Settings::from_toml("theme = \"neon\""),
+        Err(Error::InvalidSettings(_))
+    ));
+}
+
+#[test]
+fn test_app_autosave_settings_skips_last_id() {
+    let path = temp_settings_path("autosave");
+    let mut app = App::new();
+    app.settings_file = Some(path.clone());
+
+    // Typing does not rewrite the settings file.
+    app.battlenet_id = "TestUser#1234".to_string();
+    app.autosave_settings();
+    assert!(!path.exists());
+
+    app.toggle_version();
+    app.autosave_settings();
+    let saved = Settings::load_from(&path).unwrap();
+    assert_eq!(saved.version, GameVersion::Classic);
+    assert!(saved.last_id.is_empty());
+
+    // The Battle.net ID is saved on quit.
+    app.save_settings().unwrap();
+    assert_eq!(Settings::load_from(&path).unwrap().last_id, "TestUser#1234");
+    let _ = fs::remove_dir_all(path.parent().unwrap().parent().unwrap());
+}
+
+#[test]
+fn test_app_autosave_settings_reports_failure_once() {
+    let path = temp_settings_path("autosave-failure");
+    let blocker = path.parent().unwrap();
+    fs::create_dir_all(blocker.parent().unwrap()).unwrap();
+    fs::write(blocker, "not a directory").unwrap();
+
+    let mut app = App::new();
+    app.settings_file = Some(path.clone());
+    app.toggle_version();
+    app.autosave_settings();
+    let status = app.status.take().unwrap();
+    assert_eq!(status.level, StatusLevel::Error);
+    assert!(status.text.starts_with("Settings not saved: "));
+
+    // The same settings are not retried, so the failure is not shown again.
+    app.autosave_settings();
+    assert!(app.status.is_none());
+
+    // A further change is retried.
+    fs::remove_file(blocker).unwrap();
+    app.use_lowercase = true;
+    app.autosave_settings();
+    assert!(app.status.is_none());
+    assert!(Settings::load_from(&path).unwrap().lowercase);
+    let _ = fs::remove_dir_all(blocker.parent().unwrap());
+}
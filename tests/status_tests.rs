@@ -0,0 +1,24 @@
+use skillcapped_generator::status::{StatusLevel, StatusMessage, ERROR_DURATION, INFO_DURATION};
+use std::time::Duration;
+
+#[test]
+fn test_status_levels() {
+    let info = StatusMessage::info("Copied");
+    let error = StatusMessage::error("Copy failed");
+
+    assert_eq!(info.level, StatusLevel::Info);
+    assert_eq!(info.text, "Copied");
+    assert_eq!(info.duration(), INFO_DURATION);
+    assert_eq!(error.level, StatusLevel::Error);
+    assert_eq!(error.duration(), ERROR_DURATION);
+    assert!(ERROR_DURATION > INFO_DURATION);
+}
+
+#[test]
+fn test_status_expiry() {
+    let message = StatusMessage::info("Copied");
+
+    assert!(!message.is_expired(message.shown_at));
+    assert!(!message.is_expired(message.shown_at + Duration::from_millis(1999)));
+    assert!(message.is_expired(message.shown_at + INFO_DURATION));
+}
@@ -1,7 +1,8 @@
 use ratatui::{backend::CrosstermBackend, backend::TestBackend, Terminal};
 use skillcapped_generator::{
     app::App,
-    terminal::{restore_terminal, run_app, setup_terminal, update_copy_feedback},
+    status::StatusMessage,
+    terminal::{restore_terminal, run_app, setup_terminal, update_status},
 };
 use std::time::{Duration, Instant};
 
@@ -24,22 +25,31 @@ fn test_setup_and_restore_terminal() {
 }
 
 #[test]
-fn test_update_copy_feedback() {
+fn test_update_status() {
     let mut app = App::new();
 
-    // Test with no copy feedback
-    update_copy_feedback(&mut app);
-    assert!(app.copy_feedback.is_none());
+    // Test with no status
+    update_status(&mut app);
+    assert!(app.status.is_none());
 
-    // Test with recent copy feedback (should remain)
-    app.copy_feedback = Some(Instant::now());
-    update_copy_feedback(&mut app);
-    assert!(app.copy_feedback.is_some());
+    // Test with a recent message (should remain)
+    app.notify(StatusMessage::info("Copied"));
+    update_status(&mut app);
+    assert!(app.status.is_some());
 
-    // Test with old copy feedback (should be cleared)
-    app.copy_feedback = Some(Instant::now() - Duration::from_secs(2));
-    update_copy_feedback(&mut app);
-    assert!(app.copy_feedback.is_none());
+    // Test with an old info message (should be cleared)
+    let mut info = StatusMessage::info("Copied");
+    info.shown_at = Instant::now() - Duration::from_secs(2);
+    app.notify(info);
+    update_status(&mut app);
+    assert!(app.status.is_none());
+
+    // Errors stay up longer
+    let mut error = StatusMessage::error("Copy failed");
+    error.shown_at = Instant::now() - Duration::from_secs(2);
+    app.notify(error);
+    update_status(&mut app);
+    assert!(app.status.is_some());
 }
 
 #[test]
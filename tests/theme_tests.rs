@@ -0,0 +1,63 @@
+use ratatui::style::Color;
+use skillcapped_generator::theme::{select, Theme, ThemeName};
+
+#[test]
+fn test_default_theme_is_dark() {
+    let theme = Theme::default();
+
+    assert_eq!(theme.name, ThemeName::Dark);
+    assert_eq!(theme, Theme::from_name(ThemeName::Dark));
+    assert_eq!(theme.title, Color::Magenta);
+    assert_eq!(theme.highlight, Color::Yellow);
+}
+
+#[test]
+fn test_light_theme_avoids_light_colors() {
+    let theme = Theme::from_name(ThemeName::Light);
+
+    for color in [theme.text, theme.highlight, theme.muted, theme.accent] {
+        assert!(
+            ![Color::White, Color::Yellow, Color::Gray, Color::Cyan].contains(&color),
+            "{color:?} is hard to read on a light background"
+        );
+    }
+}
+
+#[test]
+fn test_monochrome_theme_has_no_colors() {
+    let theme = Theme::from_name(ThemeName::Monochrome);
+
+    for color in [
+        theme.title,
+        theme.accent,
+        theme.frame,
+        theme.highlight,
+        theme.success,
+        theme.error,
+        theme.text,
+        theme.muted,
+        theme.subtle,
+        theme.link,
+    ] {
+        assert_eq!(color, Color::Reset);
+    }
+}
+
+#[test]
+fn test_select_theme() {
+    assert_eq!(select(None, false), ThemeName::Dark);
+    // NO_COLOR switches the default to monochrome...
+    assert_eq!(select(None, true), ThemeName::Monochrome);
+    // ...but a theme chosen explicitly still wins
+    assert_eq!(select(Some(ThemeName::Light), true), ThemeName::Light);
+    assert_eq!(
+        select(Some(ThemeName::HighContrast), false),
+        ThemeName::HighContrast
+    );
+}
+
+#[test]
+fn test_theme_name_labels() {
+    assert_eq!(ThemeName::HighContrast.to_string(), "high-contrast");
+    assert_eq!(ThemeName::Monochrome.label(), "monochrome");
+}
@@ -1,6 +1,13 @@
-use ratatui::{backend::TestBackend, Terminal};
-use skillcapped_generator::{app::App, ui::draw_ui};
-use std::time::Instant;
+use ratatui::{backend::TestBackend, layout::Rect, style::Color, Terminal};
+use skillcapped_generator::{
+    app::{App, Mode},
+    clipboard::ClipboardKind,
+    keymap::{Action, KeySpec, Keymap},
+    status::StatusMessage,
+    theme::{Theme, ThemeName},
+    ui::{click_target, draw_ui, ui_layout, ClickTarget, LayoutKind},
+    version::GameVersion,
+};
 
 #[test]
 fn test_draw_ui_with_empty_input() {
@@ -36,14 +43,25 @@ fn test_draw_ui_with_invalid_battlenet_id() {
 
 #[test]
 fn test_draw_ui_with_copy_feedback() {
-    let backend = TestBackend::new(80, 24);
-    let mut terminal = Terminal::new(backend).unwrap();
     let mut app = App::new();
     app.battlenet_id = "TestUser#1234".to_string();
-    app.copy_feedback = Some(Instant::now());
+    app.notify(StatusMessage::info("Copied to clipboard via system"));
 
-    let result = terminal.draw(|f| draw_ui(f, &app));
-    assert!(result.is_ok());
+    let content = render_to_string(&app);
+    assert!(content.contains("Status"));
+    assert!(content.contains("Copied to clipboard via system"));
+    assert!(!content.contains("About"));
+}
+
+#[test]
+fn test_draw_ui_with_error_status() {
+    let mut app = App::new();
+    app.notify(StatusMessage::error(
+        "Paste failed: clipboard error: no display",
+    ));
+
+    let content = render_to_string(&app);
+    assert!(content.contains("Paste failed: clipboard error: no display"));
 }
 
 #[test]
@@ -51,7 +69,7 @@ fn test_draw_ui_classic_version() {
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
     let mut app = App::new();
-    app.version = "classic".to_string();
+    app.version = GameVersion::Classic;
 
     let result = terminal.draw(|f| draw_ui(f, &app));
     assert!(result.is_ok());
@@ -95,10 +113,9 @@ fn test_draw_ui_help_section() {
 }
 
 #[test]
-fn test_draw_ui_with_code_generation_error() {
+fn test_draw_ui_renders_every_version() {
     let mut app = App::new();
-    app.battlenet_id = "TestUser#1234".to_string();
-    app.version = "invalid_version".to_string(); // This will cause generate_code to fail
+    app.version = GameVersion::MopClassic;
 
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -112,8 +129,10 @@ fn test_draw_ui_with_code_generation_error() {
     let buffer = terminal.backend().buffer();
     let content: String = buffer.content.iter().map(|cell| cell.symbol()).collect();
 
-    // Should show "Invalid version" when code generation fails
-    assert!(content.contains("Invalid version"));
+    for version in GameVersion::ALL {
+        assert!(content.contains(version.label()));
+    }
+    assert!(content.contains("●MoP Classic●"));
 }
 
 #[test]
@@ -122,8 +141,9 @@ fn test_draw_ui_with_expired_copy_feedback() {
     app.battlenet_id = "TestUser#1234".to_string();
 
     // Set copy feedback to an old timestamp (more than 2 seconds ago)
-    let old_time = std::time::Instant::now() - std::time::Duration::from_secs(3);
-    app.copy_feedback = Some(old_time);
+    let mut status = StatusMessage::info("Copied to clipboard!");
+    status.shown_at = std::time::Instant::now() - std::time::Duration::from_secs(3);
+    app.status = Some(status);
 
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -140,6 +160,7 @@ fn test_draw_ui_with_expired_copy_feedback() {
     // Should show regular copy message, not the "Copied to clipboard!" message
     assert!(content.contains("Ctrl+C to copy"));
     assert!(!content.contains("Copied to clipboard!"));
+    assert!(content.contains("About"));
 }
 
 #[test]
@@ -164,3 +185,403 @@ fn test_create_version_span_inactive() {
     // The exact text depends on the rendering, so we check for version-related content
     assert!(content.contains("retail") || content.contains("Version"));
 }
+
+#[test]
+fn test_draw_ui_shows_first_validation_issue() {
+    let mut app = App::new();
+    app.battlenet_id = "TestUser".to_string();
+
+    let backend = TestBackend::new(80, 24);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| draw_ui(f, &app)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let content: String = buffer.content.iter().map(|cell| cell.symbol()).collect();
+
+    assert!(content.contains("missing '#' between name and number"));
+}
+
+fn render_to_string(app: &App) -> String {
+    render_sized(app, 80, 24)
+}
+
+fn render_sized(app: &App, width: u16, height: u16) -> String {
+    let backend = TestBackend::new(width, height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| draw_ui(f, app)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    buffer.content.iter().map(|cell| cell.symbol()).collect()
+}
+
+#[test]
+fn test_draw_ui_verify_mode_decodes_code() {
+    let mut app = App::new();
+    app.battlenet_id = "TestUser#1234".to_string();
+    app.verify_input = app.generate_code().unwrap();
+    app.mode = Mode::Verify;
+
+    let content = render_to_string(&app);
+
+    assert!(content.contains("Verify Unlock Code"));
+    assert!(content.contains("WA5"));
+    assert!(content.contains("Matches TestUser#1234"));
+}
+
+#[test]
+fn test_draw_ui_verify_mode_states() {
+    let mut app = App::new();
+    app.mode = Mode::Verify;
+    assert!(render_to_string(&app).contains("Paste an unlock code"));
+
+    app.verify_input = "SGVsbG8gV29ybGQ=".to_string();
+    assert!(render_to_string(&app).contains("does not match any known addon configuration"));
+}
+
+#[test]
+fn test_draw_ui_shows_addon_selector() {
+    let mut app = App::new();
+    assert!(render_to_string(&app).contains("●WA5●"));
+
+    app.cycle_addon();
+    let content = render_to_string(&app);
+    assert!(content.contains("Addon (Shift+Tab to switch)"));
+    assert!(content.contains("●WA4●"));
+}
+
+#[test]
+fn test_draw_ui_profiles_panel() {
+    use skillcapped_generator::profile::Profile;
+
+    let mut app = App::new();
+    app.mode = Mode::Profiles;
+    assert!(render_to_string(&app).contains("No profiles yet"));
+
+    app.profiles
+        .add(Profile {
+            label: "Main".to_string(),
+            battle_tag: "Xerrion#2624".to_string(),
+            version: GameVersion::Classic,
+            lowercase: true,
+        })
+        .unwrap();
+    app.start_rename_profile();
+
+    let content = render_to_string(&app);
+    assert!(content.contains("▶ Main"));
+    assert!(content.contains("Xerrion#2624"));
+    assert!(content.contains("Classic, lowercase"));
+    assert!(content.contains("Rename to: Main"));
+}
+
+#[test]
+fn test_draw_ui_history_panel() {
+    let mut app = App::new();
+    app.mode = Mode::History;
+    assert!(render_to_string(&app).contains("No codes copied yet"));
+
+    app.battlenet_id = "TestUser#1234".to_string();
+    let entry = app.history_entry().unwrap();
+    app.record_history(entry).unwrap();
+
+    let content = render_to_string(&app);
+    assert!(content.contains("History (Enter to copy again)"));
+    assert!(content.contains("▶ just now"));
+    assert!(content.contains("TestUser#1234"));
+    assert!(content.contains("WA5"));
+}
+
+#[test]
+fn test_draw_ui_places_cursor_in_input() {
+    use ratatui::layout::Position;
+
+    let mut app = App::new();
+    app.battlenet_id = "TestUser#1234".to_string();
+    app.cursor = Some(4);
+
+    let backend = TestBackend::new(80, 24);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| draw_ui(f, &app)).unwrap();
+
+    // Margin 2, border 1, then "✅ Battle.net ID: " (18 cells) and "Test"; one
+    // margin row above, since 24 rows are too few for the full margin.
+    assert_eq!(
+        terminal.get_cursor_position().unwrap(),
+        Position::new(2 + 1 + 18 + 4, 2)
+    );
+}
+
+#[test]
+fn test_help_shows_case_and_undo_keys() {
+    let mut app = App::new();
+    app.keymap = Keymap::with_overrides(
+        &[
+            (Action::ToggleCase, KeySpec::One("f3".to_string())),
+            (Action::Undo, KeySpec::One("alt+z".to_string())),
+        ]
+        .into_iter()
+        .collect(),
+    )
+    .unwrap();
+
+    let content = render_to_string(&app);
+    assert!(content.contains("F3: Lowercase on/off"));
+    assert!(content.contains("Alt+Z: Undo"));
+    assert!(content.contains("Ctrl+Y: Redo"));
+    assert!(content.contains("Lowercase: off"));
+
+    app.use_lowercase = true;
+    assert!(render_to_string(&app).contains("Lowercase: on"));
+}
+
+#[test]
+fn test_help_reflects_key_bindings() {
+    let mut app = App::new();
+    app.keymap = Keymap::with_overrides(
+        &[
+            (Action::Copy, KeySpec::One("alt+c".to_string())),
+            (Action::ToggleVersion, KeySpec::One("f2".to_string())),
+        ]
+        .into_iter()
+        .collect(),
+    )
+    .unwrap();
+
+    let content = render_to_string(&app);
+    assert!(content.contains("Alt+C: Copy"));
+    assert!(content.contains("Version (F2 to switch)"));
+    assert!(!content.contains("Ctrl+C"));
+}
+
+#[test]
+fn test_footer_shows_clipboard_backend() {
+    let mut app = App::new();
+    app.clipboard = ClipboardKind::Osc52;
+
+    let content = render_to_string(&app);
+    assert!(content.contains("Clipboard: OSC 52"));
+}
+
+#[test]
+fn test_draw_ui_paste_selection() {
+    let mut app = App::new();
+    app.paste_battlenet_id("Foo#1234, Bar#5678");
+
+    let content = render_to_string(&app);
+    assert!(content.contains("2 BattleTags found in the paste"));
+    assert!(content.contains("▶ Foo#1234"));
+    assert!(content.contains("Bar#5678"));
+    assert!(content.contains("Esc: Cancel"));
+}
+
+const SCREEN: Rect = Rect::new(0, 0, 80, 24);
+
+/// Screen position of the first cell where `text` is drawn.
+fn find_text(app: &App, text: &str) -> (u16, u16) {
+    let backend = TestBackend::new(SCREEN.width, SCREEN.height);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|f| draw_ui(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+
+    for y in 0..SCREEN.height {
+        for x in 0..SCREEN.width {
+            let matches = text.chars().enumerate().all(|(i, c)| {
+                buffer
+                    .cell((x + i as u16, y))
+                    .is_some_and(|cell| cell.symbol() == c.to_string())
+            });
+            if matches {
+                return (x, y);
+            }
+        }
+    }
+    panic!("{text:?} is not on screen");
+}
+
+#[test]
+fn test_ui_layout() {
+    let layout = ui_layout(&App::new(), SCREEN);
+
+    // The help needs three lines, so the margin shrinks to one row at the top and bottom
+    assert_eq!(layout.input, Rect::new(2, 1, 76, 4));
+    assert_eq!(layout.version.y, 5);
+    assert_eq!(layout.kind, LayoutKind::Regular);
+    assert_eq!(layout.footer, Some(Rect::new(2, 20, 76, 3)));
+
+    let layout = ui_layout(&App::new(), Rect::new(0, 0, 80, 30));
+    assert_eq!(layout.input, Rect::new(2, 2, 76, 4));
+}
+
+#[test]
+fn test_ui_layout_by_terminal_size() {
+    let app = App::new();
+
+    let layout = ui_layout(&app, Rect::new(0, 0, 80, 11));
+    assert_eq!(layout.kind, LayoutKind::TooSmall);
+
+    // Very short terminals let the sections share their borders
+    let layout = ui_layout(&app, Rect::new(0, 0, 80, 12));
+    assert_eq!(layout.kind, LayoutKind::Collapsed);
+    assert_eq!(layout.input, Rect::new(0, 0, 80, 4));
+    assert_eq!(layout.version.y, 3);
+    assert_eq!(layout.footer, None);
+
+    // The compact layout fits as soon as every section has its own borders
+    let layout = ui_layout(&app, Rect::new(0, 0, 80, 18));
+    assert_eq!(layout.kind, LayoutKind::Compact);
+
+    // Short terminals drop the margin and the About box
+    let layout = ui_layout(&app, Rect::new(0, 0, 80, 20));
+    assert_eq!(layout.kind, LayoutKind::Compact);
+    assert_eq!(layout.input, Rect::new(0, 0, 80, 4));
+    assert_eq!(layout.footer, None);
+
+    // Wide terminals put the help beside the main sections
+    let layout = ui_layout(&app, Rect::new(0, 0, 130, 30));
+    assert_eq!(layout.kind, LayoutKind::Wide);
+    assert_eq!(layout.help.y, layout.input.y);
+    assert!(layout.help.x >= layout.input.right());
+    assert_eq!(layout.footer, Some(Rect::new(2, 25, 126, 3)));
+}
+
+#[test]
+fn test_too_small_screen() {
+    let app = App::new();
+    let content = render_sized(&app, 50, 12);
+    assert!(content.contains("Terminal too small"));
+    assert!(content.contains("50x12, needs at least 60x12"));
+    assert!(!content.contains("Battle.net ID"));
+}
+
+#[test]
+fn test_collapsed_help_keeps_quit_key() {
+    let app = App::new();
+    let content = render_sized(&app, 60, 12);
+
+    assert!(content.contains("Ctrl+Q: Quit"));
+    assert!(content.contains("Ctrl+D: Decode"));
+    assert!(content.contains("Ctrl+P: Profiles"));
+    assert!(!content.contains("Redo"));
+}
+
+#[test]
+fn test_collapsed_screen() {
+    let mut app = App::new();
+    app.battlenet_id = "TestUser#1234".to_string();
+    let content = render_sized(&app, 80, 12);
+    assert!(!content.contains("Terminal too small"));
+    assert!(content.contains("Battle.net ID: TestUser#1234"));
+    assert!(content.contains("Unlock Code"));
+    assert!(content.contains("Help"));
+}
+
+#[test]
+fn test_help_wraps_on_narrow_terminal() {
+    let app = App::new();
+    // Every help item is shown, none is cut in half
+    let content = render_sized(&app, 60, 18);
+    for item in ["Esc: Clear", "Ctrl+D: Decode", "Ctrl+Q: Quit"] {
+        assert!(content.contains(item), "missing {item}");
+    }
+    // The version selector shrinks to the selected option
+    assert!(content.contains("◀ ●Retail● ▶ 1/5"));
+}
+
+#[test]
+fn test_compact_layout_shows_status_in_place_of_help() {
+    let mut app = App::new();
+    app.notify(StatusMessage::info("Copied to clipboard via auto"));
+    let content = render_sized(&app, 80, 20);
+    assert!(content.contains("Copied to clipboard via auto"));
+    assert!(!content.contains("Ctrl+Q: Quit"));
+}
+
+#[test]
+fn test_click_target_selectors() {
+    let app = App::new();
+
+    let (x, y) = find_text(&app, "Classic Era");
+    assert_eq!(
+        click_target(&app, SCREEN, x + 3, y),
+        Some(ClickTarget::Version(GameVersion::ClassicEra))
+    );
+    let (x, y) = find_text(&app, "WA4");
+    assert_eq!(
+        click_target(&app, SCREEN, x, y),
+        Some(ClickTarget::Addon("WA4".to_string()))
+    );
+    let (x, y) = find_text(&app, " | ");
+    assert_eq!(click_target(&app, SCREEN, x + 1, y), None);
+}
+
+#[test]
+fn test_click_target_code_and_link() {
+    let app = App::new();
+    let layout = ui_layout(&App::new(), SCREEN);
+
+    assert_eq!(
+        click_target(&app, SCREEN, 10, layout.code.y + 1),
+        Some(ClickTarget::Code)
+    );
+    let (x, y) = find_text(&app, "https://github.com/Xerrion");
+    assert_eq!(click_target(&app, SCREEN, x, y), Some(ClickTarget::Link));
+    assert_eq!(
+        click_target(&app, SCREEN, x + 25, y),
+        Some(ClickTarget::Link)
+    );
+    let (x, y) = find_text(&app, "Made by");
+    assert_eq!(click_target(&app, SCREEN, x, y), None);
+}
+
+#[test]
+fn test_click_target_input_position() {
+    let mut app = App::new();
+    app.battlenet_id = "TestUser#1234".to_string();
+
+    let (x, y) = find_text(&app, "TestUser");
+    assert_eq!(
+        click_target(&app, SCREEN, x + 4, y),
+        Some(ClickTarget::Input(4))
+    );
+    assert_eq!(
+        click_target(&app, SCREEN, 70, y),
+        Some(ClickTarget::Input(13))
+    );
+}
+
+#[test]
+fn test_click_target_depends_on_mode() {
+    let mut app = App::new();
+    let (x, y) = find_text(&app, "WA4");
+
+    app.mode = Mode::Verify;
+    assert_eq!(click_target(&app, SCREEN, x, y), None);
+    app.mode = Mode::History;
+    assert_eq!(click_target(&app, SCREEN, x, y), None);
+}
+
+#[test]
+fn test_draw_ui_uses_theme_colors() {
+    let render = |theme: ThemeName| {
+        let mut app = App::new();
+        app.battlenet_id = "TestUser#1234".to_string();
+        app.theme = Theme::from_name(theme);
+        let backend = TestBackend::new(80, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal.draw(|f| draw_ui(f, &app)).unwrap();
+        terminal.backend().buffer().clone()
+    };
+
+    let dark = render(ThemeName::Dark);
+    assert!(dark.content.iter().any(|cell| cell.fg == Color::Magenta));
+
+    let light = render(ThemeName::Light);
+    assert!(light.content.iter().any(|cell| cell.fg == Color::Black));
+    assert!(!light.content.iter().any(|cell| cell.fg == Color::Yellow));
+
+    let monochrome = render(ThemeName::Monochrome);
+    assert!(monochrome
+        .content
+        .iter()
+        .all(|cell| cell.fg == Color::Reset));
+}
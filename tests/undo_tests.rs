@@ -0,0 +1,79 @@
+use skillcapped_generator::undo::{EditKind, InputSnapshot, UndoStack, MAX_UNDO};
+
+fn snapshot(text: &str) -> InputSnapshot {
+    InputSnapshot {
+        text: text.to_string(),
+        cursor: None,
+    }
+}
+
+#[test]
+fn test_undo_and_redo() {
+    let mut stack = UndoStack::default();
+    assert!(stack.undo(snapshot("")).is_none());
+
+    stack.record(EditKind::Replace, snapshot("a"));
+    assert!(stack.can_undo());
+    assert!(!stack.can_redo());
+
+    assert_eq!(stack.undo(snapshot("b")), Some(snapshot("a")));
+    assert!(stack.can_redo());
+    assert_eq!(stack.redo(snapshot("a")), Some(snapshot("b")));
+    assert!(!stack.can_redo());
+}
+
+#[test]
+fn test_consecutive_edits_of_same_kind_coalesce() {
+    let mut stack = UndoStack::default();
+    stack.record(EditKind::Insert, snapshot(""));
+    stack.record(EditKind::Insert, snapshot("a"));
+    stack.record(EditKind::Delete, snapshot("ab"));
+    stack.record(EditKind::Delete, snapshot("a"));
+
+    assert_eq!(stack.undo(snapshot("")), Some(snapshot("ab")));
+    assert_eq!(stack.undo(snapshot("ab")), Some(snapshot("")));
+    assert!(!stack.can_undo());
+}
+
+#[test]
+fn test_replace_and_break_group_start_new_steps() {
+    let mut stack = UndoStack::default();
+    stack.record(EditKind::Replace, snapshot(""));
+    stack.record(EditKind::Replace, snapshot("a"));
+    stack.record(EditKind::Insert, snapshot("b"));
+    stack.break_group();
+    stack.record(EditKind::Insert, snapshot("bc"));
+
+    assert_eq!(stack.undo(snapshot("bcd")), Some(snapshot("bc")));
+    assert_eq!(stack.undo(snapshot("bc")), Some(snapshot("b")));
+    assert_eq!(stack.undo(snapshot("b")), Some(snapshot("a")));
+    assert_eq!(stack.undo(snapshot("a")), Some(snapshot("")));
+}
+
+#[test]
+fn test_new_edit_clears_redo() {
+    let mut stack = UndoStack::default();
+    stack.record(EditKind::Replace, snapshot("a"));
+    stack.undo(snapshot("b"));
+
+    stack.record(EditKind::Replace, snapshot("a"));
+
+    assert!(!stack.can_redo());
+}
+
+#[test]
+fn test_undo_stack_is_bounded() {
+    let mut stack = UndoStack::default();
+    for i in 0..MAX_UNDO + 10 {
+        stack.record(EditKind::Replace, snapshot(&i.to_string()));
+    }
+
+    let mut steps = 0;
+    let mut last = None;
+    while let Some(previous) = stack.undo(snapshot("")) {
+        steps += 1;
+        last = Some(previous);
+    }
+    assert_eq!(steps, MAX_UNDO);
+    assert_eq!(last, Some(snapshot("10")));
+}
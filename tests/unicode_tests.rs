@@ -0,0 +1,89 @@
+use skillcapped_generator::{
+    app::App,
+    battletag::{normalize, normalize_lowercase},
+};
+
+fn code_for(battlenet_id: &str, use_lowercase: bool) -> String {
+    let mut app = App::new();
+    app.battlenet_id = battlenet_id.to_string();
+    app.use_lowercase = use_lowercase;
+    app.generate_code().unwrap()
+}
+
+#[test]
+fn test_generate_code_latin_extended() {
+    assert_eq!(
+        code_for("Ærøn#1234", false),
+        "w4Zyw7huIzEyMzR2cmlkdGNldHZyZGljZQ=="
+    );
+    assert_eq!(
+        code_for("Ærøn#1234", true),
+        "w6Zyw7huIzEyMzR2cmlkdGNldHZyZGljZQ=="
+    );
+}
+
+#[test]
+fn test_generate_code_cyrillic() {
+    assert_eq!(
+        code_for("Дмитрий#1234", false),
+        "0JTQvNC40YLRgNC40LkjMTIzNHZyaWR0Y2V0dnJkaWNl"
+    );
+    assert_eq!(
+        code_for("Дмитрий#1234", true),
+        "0LTQvNC40YLRgNC40LkjMTIzNHZyaWR0Y2V0dnJkaWNl"
+    );
+}
+
+#[test]
+fn test_generate_code_korean() {
+    assert_eq!(
+        code_for("전사님#1234", false),
+        "7KCE7IKs64uYIzEyMzR2cmlkdGNldHZyZGljZQ=="
+    );
+}
+
+#[test]
+fn test_decomposed_input_encodes_like_composed() {
+    let decomposed = "E\u{301}lodie#12345";
+    let composed = "\u{c9}lodie#12345";
+
+    assert_eq!(
+        code_for(decomposed, false),
+        "w4lsb2RpZSMxMjM0NXZyaWR0Y2V0dnJkaWNl"
+    );
+    assert_eq!(code_for(decomposed, false), code_for(composed, false));
+}
+
+#[test]
+fn test_normalize() {
+    assert_eq!(normalize("E\u{301}lodie"), "\u{c9}lodie");
+    assert_eq!(normalize_lowercase("E\u{301}LODIE"), "\u{e9}lodie");
+    assert_eq!(normalize_lowercase("ДМИТРИЙ"), "дмитрий");
+}
+
+#[test]
+fn test_sanitize_input_composes_accents() {
+    let mut app = App::new();
+    app.battlenet_id = "E\u{301}lodie#12345".to_string();
+    app.sanitize_input();
+
+    assert_eq!(app.battlenet_id, "\u{c9}lodie#12345");
+    assert!(app.is_valid_battlenet_id());
+}
+
+#[test]
+fn test_validate_code_unicode_round_trip() {
+    let mut app = App::new();
+    app.battlenet_id = "Ærøn#1234".to_string();
+
+    app.use_lowercase = true;
+    let lowercase_code = app.generate_code().unwrap();
+    app.use_lowercase = false;
+    let code = app.generate_code().unwrap();
+
+    assert!(app.validate_code(&lowercase_code));
+    assert!(app.validate_code(&code));
+
+    app.battlenet_id = "A\u{30a}ron#1234".to_string();
+    assert!(!app.validate_code(&code));
+}
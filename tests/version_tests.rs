@@ -0,0 +1,50 @@
+use skillcapped_generator::version::GameVersion;
+
+#[test]
+fn test_default_version() {
+    assert_eq!(GameVersion::default(), GameVersion::Retail);
+}
+
+#[test]
+fn test_display_round_trips_through_from_str() {
+    for version in GameVersion::ALL {
+        let parsed: GameVersion = version.to_string().parse().unwrap();
+        assert_eq!(parsed, version);
+    }
+}
+
+#[test]
+fn test_from_str_is_lenient_about_case_and_separators() {
+    assert_eq!("Retail".parse(), Ok(GameVersion::Retail));
+    assert_eq!("classic_era".parse(), Ok(GameVersion::ClassicEra));
+    assert_eq!(" Cata Classic ".parse(), Ok(GameVersion::CataclysmClassic));
+    assert_eq!("MOP-CLASSIC".parse(), Ok(GameVersion::MopClassic));
+}
+
+#[test]
+fn test_from_str_rejects_unknown_versions() {
+    assert!("invalid_version".parse::<GameVersion>().is_err());
+    assert!("".parse::<GameVersion>().is_err());
+}
+
+#[test]
+fn test_next_cycles_through_all_versions() {
+    let mut version = GameVersion::Retail;
+    for expected in GameVersion::ALL
+        .iter()
+        .cycle()
+        .skip(1)
+        .take(GameVersion::ALL.len())
+    {
+        version = version.next();
+        assert_eq!(version, *expected);
+    }
+    assert_eq!(version, GameVersion::Retail);
+}
+
+#[test]
+fn test_labels() {
+    assert_eq!(GameVersion::Retail.label(), "Retail");
+    assert_eq!(GameVersion::CataclysmClassic.label(), "Cata Classic");
+    assert_eq!(GameVersion::MopClassic.label(), "MoP Classic");
+}